@@ -3,6 +3,8 @@ use cc_traits::{Back, Collection, PushBack};
 use std::collections::VecDeque;
 
 /// Ordered stack.
+///
+/// See `cc_traits::Sorted` for a complete sorted collection.
 #[derive(Default)]
 pub struct Ordered<S> {
 	inner: S,
@@ -26,10 +26,19 @@ pub trait StackMut<T> = Stack<T> + BackMut + PushBack + PopBack;
 /// A Vec is essentially a [`Stack`] indexable by a `usize`.
 pub trait Vec<T> = Stack<T> + Index<usize, Output = T>;
 
+/// Mutable Vec data structure.
+///
+/// This trait alias describes the mutables operations on a Vec,
+/// including sorting (with the `alloc` feature).
+/// See [`Vec`] for more details.
+#[cfg(feature = "alloc")]
+pub trait VecMut<T> = Vec<T> + StackMut<T> + IndexMut<usize> + Sort;
+
 /// Mutable Vec data structure.
 ///
 /// This trait alias describes the mutables operations on a Vec.
 /// See [`Vec`] for more details.
+#[cfg(not(feature = "alloc"))]
 pub trait VecMut<T> = Vec<T> + StackMut<T> + IndexMut<usize>;
 
 /// Immutable double-ended queue.
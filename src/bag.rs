@@ -0,0 +1,290 @@
+//! Multiset.
+use crate::{
+	Clear, Collection, CollectionRef, Get, GetKeyValue, GetMut, Insert, Keyed, KeyedRef, Len,
+	MapInsert, MapIter, Remove,
+};
+
+/// Multiset (or bag), counting the number of occurrences of each item.
+///
+/// The counts are stored in any map `M` from items to `usize`
+/// (such as a [`MapMut<T, usize>`](crate::MapMut)).
+/// The length of a bag is the total number of occurrences of its items,
+/// while [`Get`] (and hence the [`Set`](crate::Set) interface) considers
+/// the distinct items.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Bag, Get, Insert, Len};
+/// use std::collections::HashMap;
+///
+/// let mut bag: Bag<HashMap<char, usize>> = Bag::new();
+/// for c in "hello".chars() {
+///   bag.insert(c);
+/// }
+///
+/// assert_eq!(bag.len(), 5);
+/// assert_eq!(bag.distinct_len(), 4);
+/// assert_eq!(bag.count(&'l'), 2);
+/// assert!(bag.contains(&'h'));
+///
+/// assert_eq!(bag.remove_one(&'l'), Some(1));
+/// assert_eq!(bag.remove_one(&'l'), Some(0));
+/// assert_eq!(bag.remove_one(&'l'), None);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct Bag<M> {
+	counts: M,
+	len: usize,
+}
+
+impl<M> Bag<M> {
+	/// Creates a new empty bag.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		M: Default,
+	{
+		Self {
+			counts: M::default(),
+			len: 0,
+		}
+	}
+
+	/// Returns a reference to the inner map of counts.
+	#[inline(always)]
+	pub fn counts(&self) -> &M {
+		&self.counts
+	}
+
+	/// Consumes the bag and returns the inner map of counts.
+	#[inline(always)]
+	pub fn into_counts(self) -> M {
+		self.counts
+	}
+
+	/// Returns the number of distinct items in the bag.
+	#[inline(always)]
+	pub fn distinct_len(&self) -> usize
+	where
+		M: Len,
+	{
+		self.counts.len()
+	}
+
+	/// Returns the number of occurrences of the given item.
+	#[inline(always)]
+	pub fn count<'a, Q: ?Sized>(&self, item: &'a Q) -> usize
+	where
+		M: Get<&'a Q> + Collection<Item = usize>,
+	{
+		self.counts.get(item).map(|n| *n).unwrap_or(0)
+	}
+
+	/// Removes one occurrence of the given item.
+	///
+	/// Returns the remaining number of occurrences of the item,
+	/// or `None` if the item was not in the bag.
+	pub fn remove_one<'a, Q: ?Sized>(&mut self, item: &'a Q) -> Option<usize>
+	where
+		M: GetMut<&'a Q> + Remove<&'a Q> + Collection<Item = usize>,
+	{
+		let remaining = {
+			let mut n = self.counts.get_mut(item)?;
+			*n -= 1;
+			*n
+		};
+
+		if remaining == 0 {
+			self.counts.remove(item);
+		}
+
+		self.len -= 1;
+		Some(remaining)
+	}
+
+	/// Removes every occurrence of the given item.
+	///
+	/// Returns the number of removed occurrences.
+	pub fn remove_all<'a, Q: ?Sized>(&mut self, item: &'a Q) -> usize
+	where
+		M: Remove<&'a Q> + Collection<Item = usize>,
+	{
+		let n = self.counts.remove(item).unwrap_or(0);
+		self.len -= n;
+		n
+	}
+
+	/// Returns an iterator over the items of the bag,
+	/// where each item is repeated by its number of occurrences.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, M>
+	where
+		M: MapIter + Collection<Item = usize>,
+	{
+		Iter {
+			counts: self.counts.iter(),
+			current: None,
+			len: self.len,
+		}
+	}
+}
+
+impl<M: Keyed> Collection for Bag<M> {
+	type Item = M::Key;
+}
+
+impl<M: KeyedRef> CollectionRef for Bag<M> {
+	type ItemRef<'a> = M::KeyRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_key_ref(r)
+	}
+}
+
+impl<M> Len for Bag<M> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+}
+
+impl<'a, Q: ?Sized, M> Get<&'a Q> for Bag<M>
+where
+	M: GetKeyValue<&'a Q>,
+{
+	#[inline(always)]
+	fn get(&self, item: &'a Q) -> Option<M::KeyRef<'_>> {
+		self.counts.get_key_value(item).map(|(k, _)| k)
+	}
+}
+
+impl<M> Insert for Bag<M>
+where
+	M: Keyed<Item = usize> + MapInsert<<M as Keyed>::Key> + for<'a> GetMut<&'a <M as Keyed>::Key>,
+{
+	type Output = usize;
+
+	/// Inserts one occurrence of the given item.
+	///
+	/// Returns the new number of occurrences of the item.
+	fn insert(&mut self, item: M::Key) -> usize {
+		self.len += 1;
+
+		if let Some(mut n) = self.counts.get_mut(&item) {
+			*n += 1;
+			return *n;
+		}
+
+		self.counts.insert(item, 1);
+		1
+	}
+}
+
+impl<M: Clear> Clear for Bag<M> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.counts.clear();
+		self.len = 0
+	}
+}
+
+impl<M> crate::Iter for Bag<M>
+where
+	M: MapIter + Collection<Item = usize>,
+{
+	type Iter<'a> = Iter<'a, M> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+/// Iterator over the items of a [`Bag`],
+/// where each item is repeated by its number of occurrences.
+pub struct Iter<'a, M: MapIter + 'a> {
+	counts: <M as MapIter>::Iter<'a>,
+	current: Option<(M::KeyRef<'a>, usize)>,
+	len: usize,
+}
+
+impl<'a, M> Iterator for Iter<'a, M>
+where
+	M: MapIter + Collection<Item = usize>,
+{
+	type Item = M::KeyRef<'a>;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			match &mut self.current {
+				Some((item, n)) if *n > 0 => {
+					*n -= 1;
+					self.len -= 1;
+					return Some(item.clone());
+				}
+				_ => {
+					let (item, n) = self.counts.next()?;
+					self.current = Some((item, *n))
+				}
+			}
+		}
+	}
+}
+
+impl<'a, M> ExactSizeIterator for Iter<'a, M> where M: MapIter + Collection<Item = usize> {}
+
+#[cfg(test)]
+mod tests {
+	use super::Bag;
+	use crate::{Clear, Get, Insert, Len};
+	use std::collections::BTreeMap;
+
+	#[test]
+	fn lengths_track_occurrences() {
+		let mut bag: Bag<BTreeMap<char, usize>> = Bag::new();
+		assert_eq!(bag.insert('a'), 1);
+		assert_eq!(bag.insert('b'), 1);
+		assert_eq!(bag.insert('a'), 2);
+		assert_eq!((bag.len(), bag.distinct_len()), (3, 2));
+
+		assert_eq!(bag.remove_one(&'a'), Some(1));
+		assert_eq!(bag.remove_one(&'c'), None);
+		assert_eq!((bag.len(), bag.distinct_len()), (2, 2));
+
+		assert_eq!(bag.remove_one(&'a'), Some(0));
+		assert!(!bag.contains(&'a'));
+		assert_eq!(bag.count(&'a'), 0);
+		assert_eq!((bag.len(), bag.distinct_len()), (1, 1));
+	}
+
+	#[test]
+	fn remove_all() {
+		let mut bag: Bag<BTreeMap<char, usize>> = Bag::new();
+		for c in "abracadabra".chars() {
+			bag.insert(c);
+		}
+
+		assert_eq!(bag.remove_all(&'a'), 5);
+		assert_eq!(bag.remove_all(&'a'), 0);
+		assert_eq!(bag.len(), 6);
+		assert_eq!(bag.iter().collect::<String>(), "bbcdrr");
+
+		bag.clear();
+		assert!(bag.is_empty());
+		assert_eq!(bag.iter().next(), None);
+	}
+}
@@ -0,0 +1,247 @@
+//! Bidirectional map.
+use crate::{Clear, Collection, Get, Keyed, Len, MapInsert, Remove};
+
+/// Pairs removed by the insertion of a new pair in a [`BiMap`](crate::BiMap).
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Overwritten<L, R> {
+	/// No pair has been removed.
+	Neither,
+
+	/// The inserted pair was already present.
+	Pair(L, R),
+
+	/// The pair sharing the left value has been removed.
+	Left(L, R),
+
+	/// The pair sharing the right value has been removed.
+	Right(L, R),
+
+	/// The pair sharing the left value (first) and the pair sharing
+	/// the right value (second) have been removed.
+	Both((L, R), (L, R)),
+}
+
+impl<L, R> Overwritten<L, R> {
+	/// Checks if at least one pair has been removed.
+	#[inline(always)]
+	pub fn did_overwrite(&self) -> bool {
+		!matches!(self, Self::Neither)
+	}
+}
+
+/// Bidirectional map backed by two maps.
+///
+/// The left map `LM` associates left values to right values, and the right
+/// map `RM` associates right values to left values.
+/// Both maps are kept in sync, which requires the left and right values
+/// to be [`Clone`].
+/// They can be accessed with [`BiMap::left`] and [`BiMap::right`] to view the
+/// bidirectional map as a [`Map<L, R>`](crate::Map) or a
+/// [`Map<R, L>`](crate::Map).
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{bi_map, BiMap as _, BiMapMut, Get, Overwritten};
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut symbols: bi_map::BiMap<HashMap<&str, u32>, BTreeMap<u32, &str>> =
+///   bi_map::BiMap::new();
+/// assert_eq!(symbols.insert("x", 0), Overwritten::Neither);
+/// assert_eq!(symbols.insert("y", 1), Overwritten::Neither);
+///
+/// assert_eq!(symbols.get_by_left(&"x"), Some(&0));
+/// assert_eq!(symbols.get_by_right(&1), Some(&"y"));
+/// assert_eq!(symbols.insert("y", 1), Overwritten::Pair("y", 1));
+///
+/// assert_eq!(symbols.insert("x", 1), Overwritten::Both(("x", 0), ("y", 1)));
+/// assert!(symbols.right().contains(&1));
+/// assert!(!symbols.right().contains(&0));
+///
+/// assert_eq!(symbols.remove_by_right(&1), Some(("x", 1)));
+/// assert!(symbols.left().is_empty());
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct BiMap<LM, RM> {
+	left: LM,
+	right: RM,
+}
+
+impl<LM, RM> BiMap<LM, RM> {
+	/// Creates a new empty bidirectional map.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		LM: Default,
+		RM: Default,
+	{
+		Self {
+			left: LM::default(),
+			right: RM::default(),
+		}
+	}
+
+	/// Returns a reference to the map from left values to right values.
+	#[inline(always)]
+	pub fn left(&self) -> &LM {
+		&self.left
+	}
+
+	/// Returns a reference to the map from right values to left values.
+	#[inline(always)]
+	pub fn right(&self) -> &RM {
+		&self.right
+	}
+
+	/// Consumes the bidirectional map and returns the left and right maps.
+	#[inline(always)]
+	pub fn into_inner(self) -> (LM, RM) {
+		(self.left, self.right)
+	}
+}
+
+impl<LM: Len, RM> Len for BiMap<LM, RM> {
+	/// Returns the number of pairs in the map.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.left.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.left.is_empty()
+	}
+}
+
+impl<LM: Clear, RM: Clear> Clear for BiMap<LM, RM> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.left.clear();
+		self.right.clear()
+	}
+}
+
+impl<L, R, LM, RM> crate::BiMap<L, R> for BiMap<LM, RM>
+where
+	LM: Keyed<Key = L> + Collection<Item = R> + for<'a> Get<&'a L>,
+	RM: Keyed<Key = R> + Collection<Item = L> + for<'a> Get<&'a R>,
+{
+	type LeftRef<'a> = RM::ItemRef<'a> where Self: 'a;
+
+	type RightRef<'a> = LM::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn get_by_left(&self, left: &L) -> Option<LM::ItemRef<'_>> {
+		self.left.get(left)
+	}
+
+	#[inline(always)]
+	fn get_by_right(&self, right: &R) -> Option<RM::ItemRef<'_>> {
+		self.right.get(right)
+	}
+}
+
+impl<L, R, LM, RM> crate::BiMapMut<L, R> for BiMap<LM, RM>
+where
+	L: Clone,
+	R: Clone,
+	LM: Keyed<Key = L>
+		+ Collection<Item = R>
+		+ MapInsert<L>
+		+ for<'a> Get<&'a L>
+		+ for<'a> Remove<&'a L>,
+	RM: Keyed<Key = R>
+		+ Collection<Item = L>
+		+ MapInsert<R>
+		+ for<'a> Get<&'a R>
+		+ for<'a> Remove<&'a R>,
+{
+	fn insert(&mut self, left: L, right: R) -> Overwritten<L, R> {
+		let by_left = self.remove_by_left(&left);
+		let by_right = self.remove_by_right(&right);
+
+		self.left.insert(left.clone(), right.clone());
+		self.right.insert(right, left);
+
+		match (by_left, by_right) {
+			(None, None) => Overwritten::Neither,
+			(Some((l, r)), None) => {
+				// `r` is still in the right map only if it is the inserted right value.
+				if self.right.contains(&r) {
+					Overwritten::Pair(l, r)
+				} else {
+					Overwritten::Left(l, r)
+				}
+			}
+			(None, Some((l, r))) => Overwritten::Right(l, r),
+			(Some(a), Some(b)) => Overwritten::Both(a, b),
+		}
+	}
+
+	fn remove_by_left(&mut self, left: &L) -> Option<(L, R)> {
+		let right = self.left.remove(left)?;
+		let left = self.right.remove(&right)?;
+		Some((left, right))
+	}
+
+	fn remove_by_right(&mut self, right: &R) -> Option<(L, R)> {
+		let left = self.right.remove(right)?;
+		let right = self.left.remove(&left)?;
+		Some((left, right))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{BiMap, Overwritten};
+	use crate::{BiMap as _, BiMapMut, Clear, Len};
+	use std::collections::BTreeMap;
+
+	type Symbols = BiMap<BTreeMap<char, u32>, BTreeMap<u32, char>>;
+
+	/// Checks that both maps hold the same pairs.
+	fn assert_in_sync(map: &Symbols) {
+		assert_eq!(map.left().len(), map.right().len());
+		for (l, r) in map.left() {
+			assert_eq!(map.right().get(r), Some(l));
+		}
+	}
+
+	#[test]
+	fn overwrites() {
+		let mut map = Symbols::new();
+		assert_eq!(map.insert('a', 0), Overwritten::Neither);
+		assert_eq!(map.insert('b', 1), Overwritten::Neither);
+
+		assert_eq!(map.insert('a', 2), Overwritten::Left('a', 0));
+		assert!(!map.contains_right(&0));
+		assert_in_sync(&map);
+
+		assert_eq!(map.insert('c', 2), Overwritten::Right('a', 2));
+		assert!(!map.contains_left(&'a'));
+		assert_in_sync(&map);
+
+		assert_eq!(map.insert('c', 2), Overwritten::Pair('c', 2));
+		assert!(!Overwritten::<char, u32>::Neither.did_overwrite());
+		assert_eq!(map.len(), 2);
+		assert_in_sync(&map);
+	}
+
+	#[test]
+	fn removal() {
+		let mut map = Symbols::new();
+		map.insert('a', 0);
+		map.insert('b', 1);
+
+		assert_eq!(map.remove_by_left(&'c'), None);
+		assert_eq!(map.remove_by_right(&2), None);
+		assert_eq!(map.remove_by_left(&'a'), Some(('a', 0)));
+		assert_eq!(map.get_by_right(&0), None);
+		assert_eq!(map.remove_by_right(&1), Some(('b', 1)));
+		assert!(map.is_empty());
+
+		map.insert('a', 0);
+		map.clear();
+		assert!(map.left().is_empty() && map.right().is_empty());
+	}
+}
@@ -0,0 +1,302 @@
+//! Collection wrapper with a maximum length.
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, Get, GetMut, Insert, Len, MapInsert, PopBack,
+	PopFront, PushBack, Remove,
+};
+
+/// Eviction policy of a [`Bounded`] collection.
+pub trait Eviction<C> {
+	/// Makes room for a new item in the given full collection.
+	///
+	/// Returns `false` if no item has been evicted,
+	/// in which case the insertion is rejected.
+	fn evict(&self, collection: &mut C) -> bool;
+}
+
+/// Eviction policy rejecting any insertion in a full collection.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Reject;
+
+impl<C> Eviction<C> for Reject {
+	#[inline(always)]
+	fn evict(&self, _collection: &mut C) -> bool {
+		false
+	}
+}
+
+/// Eviction policy removing the oldest item (using [`PopFront`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DropOldest;
+
+impl<C: PopFront> Eviction<C> for DropOldest {
+	#[inline(always)]
+	fn evict(&self, collection: &mut C) -> bool {
+		collection.pop_front().is_some()
+	}
+}
+
+/// Eviction policy removing the newest item (using [`PopBack`]).
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct DropNewest;
+
+impl<C: PopBack> Eviction<C> for DropNewest {
+	#[inline(always)]
+	fn evict(&self, collection: &mut C) -> bool {
+		collection.pop_back().is_some()
+	}
+}
+
+/// Collection with a maximum length.
+///
+/// Insertion operations ([`PushBack`], [`Insert`] and [`MapInsert`]) are fallible:
+/// when the collection is full, an item is first evicted according to the
+/// eviction policy `P` ([`Reject`], [`DropOldest`] or [`DropNewest`]).
+/// If no item can be evicted, the insertion is rejected and the element is
+/// given back in the `Err` variant of the insertion output.
+/// Replacing the value of a key already present in a map, or inserting an
+/// item already present in a set, never evicts nor rejects.
+///
+/// The maximum length is enforced lazily: a collection wrapped while
+/// already holding more items than the maximum length keeps them until
+/// the next insertion, which evicts items until there is room for the new one
+/// (or rejects it).
+///
+/// The [`Capacity`] of a bounded collection is its maximum length.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{bounded::DropOldest, Bounded, PushBack};
+/// use std::collections::VecDeque;
+///
+/// let mut rejecting = Bounded::new(Vec::new(), 2);
+/// assert!(rejecting.push_back(1).is_ok());
+/// assert!(rejecting.push_back(2).is_ok());
+/// assert_eq!(rejecting.push_back(3), Err(3));
+///
+/// let mut buffer = Bounded::with_policy(VecDeque::new(), 2, DropOldest);
+/// assert!(buffer.push_back(1).is_ok());
+/// assert!(buffer.push_back(2).is_ok());
+/// assert!(buffer.push_back(3).is_ok());
+/// assert_eq!(buffer.inner(), &[2, 3]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Bounded<C, P = Reject> {
+	inner: C,
+	max_len: usize,
+	policy: P,
+}
+
+impl<C> Bounded<C> {
+	/// Creates a new bounded collection rejecting
+	/// insertions beyond the given maximum length.
+	#[inline(always)]
+	pub fn new(inner: C, max_len: usize) -> Self {
+		Self::with_policy(inner, max_len, Reject)
+	}
+}
+
+impl<C, P> Bounded<C, P> {
+	/// Creates a new bounded collection with the given eviction policy.
+	#[inline(always)]
+	pub fn with_policy(inner: C, max_len: usize, policy: P) -> Self {
+		Self {
+			inner,
+			max_len,
+			policy,
+		}
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Returns the maximum length of the collection.
+	#[inline(always)]
+	pub fn max_len(&self) -> usize {
+		self.max_len
+	}
+
+	/// Returns a reference to the eviction policy.
+	#[inline(always)]
+	pub fn policy(&self) -> &P {
+		&self.policy
+	}
+
+	/// Consumes the wrapper and returns the inner collection.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+
+	/// Checks that the collection can receive a new item,
+	/// evicting items until its length is below the maximum length.
+	fn make_room(&mut self) -> bool
+	where
+		C: Len,
+		P: Eviction<C>,
+	{
+		while self.inner.len() >= self.max_len {
+			if !self.policy.evict(&mut self.inner) {
+				return false;
+			}
+		}
+
+		true
+	}
+}
+
+crate::macros::forward_read!(Bounded<C, P>.inner);
+
+impl<C, P> Capacity for Bounded<C, P> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.max_len
+	}
+}
+
+impl<C: CollectionMut, P> CollectionMut for Bounded<C, P> {
+	type ItemMut<'a> = C::ItemMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<Q, C: GetMut<Q>, P> GetMut<Q> for Bounded<C, P> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+		self.inner.get_mut(key)
+	}
+}
+
+impl<C: Len + PushBack, P: Eviction<C>> PushBack for Bounded<C, P> {
+	type Output = Result<C::Output, C::Item>;
+
+	#[inline(always)]
+	fn push_back(&mut self, element: C::Item) -> Self::Output {
+		if self.make_room() {
+			Ok(self.inner.push_back(element))
+		} else {
+			Err(element)
+		}
+	}
+}
+
+impl<C, P> Insert for Bounded<C, P>
+where
+	C: Len + Insert + for<'a> Get<&'a <C as Collection>::Item>,
+	P: Eviction<C>,
+{
+	type Output = Result<C::Output, C::Item>;
+
+	#[inline(always)]
+	fn insert(&mut self, element: C::Item) -> Self::Output {
+		if self.inner.contains(&element) || self.make_room() {
+			Ok(self.inner.insert(element))
+		} else {
+			Err(element)
+		}
+	}
+}
+
+impl<K, C, P> MapInsert<K> for Bounded<C, P>
+where
+	C: Len + MapInsert<K> + for<'a> Get<&'a K>,
+	P: Eviction<C>,
+{
+	type Output = Result<C::Output, (K, C::Item)>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: C::Item) -> Self::Output {
+		if self.inner.contains(&key) || self.make_room() {
+			Ok(self.inner.insert(key, value))
+		} else {
+			Err((key, value))
+		}
+	}
+}
+
+impl<C: PopBack, P> PopBack for Bounded<C, P> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<C::Item> {
+		self.inner.pop_back()
+	}
+}
+
+impl<C: PopFront, P> PopFront for Bounded<C, P> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<C::Item> {
+		self.inner.pop_front()
+	}
+}
+
+impl<Q, C: Remove<Q>, P> Remove<Q> for Bounded<C, P> {
+	#[inline(always)]
+	fn remove(&mut self, key: Q) -> Option<C::Item> {
+		self.inner.remove(key)
+	}
+}
+
+impl<C: Clear, P> Clear for Bounded<C, P> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.inner.clear()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Bounded, DropNewest, DropOldest};
+	use crate::{Insert, Len, MapInsert, PushBack};
+	use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+	#[test]
+	fn overfull_collection_is_trimmed_on_insertion() {
+		let mut buffer = Bounded::with_policy(VecDeque::from(vec![1, 2, 3, 4, 5]), 2, DropOldest);
+		assert_eq!(buffer.push_back(6), Ok(()));
+		assert_eq!(buffer.len(), 2);
+		assert_eq!(buffer.inner(), &[5, 6]);
+
+		let mut stack = Bounded::with_policy(vec![1, 2, 3], 2, DropNewest);
+		assert_eq!(stack.push_back(4), Ok(()));
+		assert_eq!(stack.inner(), &[1, 4]);
+	}
+
+	#[test]
+	fn overfull_collection_rejects() {
+		let mut rejecting = Bounded::new(vec![1, 2, 3], 2);
+		assert_eq!(rejecting.push_back(4), Err(4));
+		assert_eq!(rejecting.len(), 3);
+	}
+
+	#[test]
+	fn zero_max_len() {
+		let mut rejecting = Bounded::new(Vec::new(), 0);
+		assert_eq!(rejecting.push_back(1), Err(1));
+
+		let mut evicting = Bounded::with_policy(VecDeque::new(), 0, DropOldest);
+		assert_eq!(evicting.push_back(1), Err(1));
+		assert!(evicting.is_empty());
+	}
+
+	#[test]
+	fn present_items_are_not_rejected() {
+		let mut set = Bounded::new(BTreeSet::new(), 2);
+		assert_eq!(set.insert(1), Ok(true));
+		assert_eq!(set.insert(2), Ok(true));
+		assert_eq!(set.insert(1), Ok(false));
+		assert_eq!(set.insert(3), Err(3));
+
+		let mut map = Bounded::new(BTreeMap::new(), 1);
+		assert_eq!(map.insert("a", 1), Ok(None));
+		assert_eq!(map.insert("a", 2), Ok(Some(1)));
+		assert_eq!(map.insert("b", 3), Err(("b", 3)));
+	}
+}
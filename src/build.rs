@@ -0,0 +1,206 @@
+use crate::{
+	Insert, InsertAll, Keyed, MapInsert, MapInsertAll, PushBack, PushBackAll, WithCapacity,
+};
+
+/// Creates an empty collection with the given capacity.
+pub(crate) fn empty<C: Default + WithCapacity>(capacity: usize) -> C {
+	match capacity {
+		0 => C::default(),
+		capacity => C::with_capacity(capacity),
+	}
+}
+
+/// Iterator extension building collections from the iterated items.
+///
+/// Unlike [`Iterator::collect`], which requires a [`FromIterator`](core::iter::FromIterator)
+/// implementation, this only relies on the traits of this crate, so that
+/// generic code can build any collection implementing them.
+/// The collection is created with [`WithCapacity`] using the lower bound of
+/// the iterator size hint.
+/// The `*_into` methods add the items to an existing collection instead,
+/// using its bulk insertion ([`PushBackAll`], [`InsertAll`] or
+/// [`MapInsertAll`]).
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Build, Get};
+/// use std::collections::{HashMap, VecDeque};
+///
+/// let deque: VecDeque<i32> = (1..4).build();
+/// assert_eq!(deque, [1, 2, 3]);
+///
+/// let map: HashMap<&str, i32> = vec![("a", 1), ("b", 2)].into_iter().build_map();
+/// assert_eq!(map.get("b"), Some(&2));
+///
+/// let mut v = vec![0];
+/// (1..4).build_into(&mut v);
+/// assert_eq!(v, [0, 1, 2, 3]);
+/// ```
+pub trait Build: Iterator + Sized {
+	/// Builds a collection by pushing each item on its back.
+	fn build<C>(self) -> C
+	where
+		C: Default + WithCapacity + PushBack<Item = Self::Item>,
+	{
+		let mut result: C = empty(self.size_hint().0);
+		for item in self {
+			result.push_back(item);
+		}
+
+		result
+	}
+
+	/// Builds a collection by inserting each item.
+	fn build_set<C>(self) -> C
+	where
+		C: Default + WithCapacity + Insert<Item = Self::Item>,
+	{
+		let mut result: C = empty(self.size_hint().0);
+		for item in self {
+			result.insert(item);
+		}
+
+		result
+	}
+
+	/// Builds a map by inserting each key-value pair.
+	fn build_map<C>(self) -> C
+	where
+		Self: Iterator<Item = (C::Key, C::Item)>,
+		C: Default + WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+	{
+		let mut result: C = empty(self.size_hint().0);
+		for (key, value) in self {
+			result.insert(key, value);
+		}
+
+		result
+	}
+
+	/// Pushes each item on the back of the given collection.
+	#[inline(always)]
+	fn build_into<C>(self, collection: &mut C)
+	where
+		C: PushBackAll<Item = Self::Item>,
+	{
+		collection.push_back_all(self)
+	}
+
+	/// Inserts each item in the given collection.
+	#[inline(always)]
+	fn build_set_into<C>(self, collection: &mut C)
+	where
+		C: InsertAll<Item = Self::Item>,
+	{
+		collection.insert_all(self)
+	}
+
+	/// Inserts each key-value pair in the given map.
+	#[inline(always)]
+	fn build_map_into<C>(self, map: &mut C)
+	where
+		Self: Iterator<Item = (C::Key, C::Item)>,
+		C: Keyed + MapInsertAll<<C as Keyed>::Key>,
+	{
+		map.insert_all(self)
+	}
+}
+
+impl<I: Iterator> Build for I {}
+
+/// Converts a collection into another collection with the same items,
+/// pushing each item on the back of the target collection.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::convert;
+/// use std::collections::VecDeque;
+///
+/// let deque = convert::<VecDeque<_>>(vec![1, 2, 3]);
+/// assert_eq!(deque, [1, 2, 3]);
+/// ```
+#[inline(always)]
+pub fn convert<D>(collection: impl IntoIterator<Item = D::Item>) -> D
+where
+	D: Default + WithCapacity + PushBack,
+{
+	collection.into_iter().build()
+}
+
+/// Converts a collection into a set, inserting each item.
+#[inline(always)]
+pub fn convert_set<D>(collection: impl IntoIterator<Item = D::Item>) -> D
+where
+	D: Default + WithCapacity + Insert,
+{
+	collection.into_iter().build_set()
+}
+
+/// Converts a collection of key-value pairs into a map, inserting each pair.
+#[inline(always)]
+pub fn convert_map<D>(collection: impl IntoIterator<Item = (D::Key, D::Item)>) -> D
+where
+	D: Default + WithCapacity + Keyed + MapInsert<<D as Keyed>::Key>,
+{
+	collection.into_iter().build_map()
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{convert, convert_map, convert_set, Build};
+	use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+	#[test]
+	fn build_collections() {
+		let deque: VecDeque<i32> = (1..4).build();
+		assert_eq!(deque, [1, 2, 3]);
+
+		let set: HashSet<i32> = vec![1, 2, 1].into_iter().build_set();
+		assert_eq!(set, [1, 2].iter().copied().collect());
+
+		let map: HashMap<&str, i32> = vec![("a", 1), ("a", 2)].into_iter().build_map();
+		assert_eq!(map.len(), 1);
+		assert_eq!(map["a"], 2);
+	}
+
+	#[test]
+	fn convert_collections() {
+		let deque = convert::<VecDeque<_>>(vec![1, 2, 3]);
+		assert_eq!(deque, [1, 2, 3]);
+
+		let set = convert_set::<HashSet<_>>(vec![2, 1, 2]);
+		assert_eq!(set, [1, 2].iter().copied().collect());
+
+		let map = convert_map::<HashMap<_, _>>(BTreeMap::from([("a", 1), ("b", 2)]));
+		assert_eq!(map, HashMap::from([("a", 1), ("b", 2)]));
+	}
+
+	#[test]
+	fn build_into_collections() {
+		let mut v = vec![0];
+		(1..4).build_into(&mut v);
+		assert_eq!(v, [0, 1, 2, 3]);
+
+		let mut set: HashSet<i32> = [1].iter().copied().collect();
+		vec![1, 2].into_iter().build_set_into(&mut set);
+		assert_eq!(set, [1, 2].iter().copied().collect());
+
+		let mut set = BTreeSet::new();
+		(0..3).build_set_into(&mut set);
+		assert_eq!(set.len(), 3);
+
+		let mut map = HashMap::from([("a", 1)]);
+		vec![("a", 2), ("b", 3)]
+			.into_iter()
+			.build_map_into(&mut map);
+		assert_eq!(map, HashMap::from([("a", 2), ("b", 3)]));
+
+		let mut map = BTreeMap::new();
+		vec![("b", 1), ("a", 2)]
+			.into_iter()
+			.build_map_into(&mut map);
+		assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 2), ("b", 1)]);
+	}
+}
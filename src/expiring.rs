@@ -0,0 +1,612 @@
+//! Map with expiring entries.
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef,
+	Len, MapInsert, MapIter, Remove, SimpleCollectionMut, SimpleCollectionRef,
+};
+use alloc::vec::Vec;
+use core::{cell::Cell, time::Duration};
+
+/// Source of time used to expire the entries of an [`Expiring`] map.
+pub trait Clock {
+	/// Point in time.
+	type Instant: Copy + Ord;
+
+	/// Returns the current instant.
+	fn now(&self) -> Self::Instant;
+
+	/// Returns the instant `duration` after `instant`, saturating at the
+	/// latest representable instant.
+	fn saturating_add(&self, instant: Self::Instant, duration: Duration) -> Self::Instant;
+}
+
+impl<C: Clock> Clock for &C {
+	type Instant = C::Instant;
+
+	#[inline(always)]
+	fn now(&self) -> C::Instant {
+		C::now(self)
+	}
+
+	#[inline(always)]
+	fn saturating_add(&self, instant: C::Instant, duration: Duration) -> C::Instant {
+		C::saturating_add(self, instant, duration)
+	}
+}
+
+/// System clock, using [`std::time::Instant`].
+#[cfg(feature = "std")]
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct SystemClock;
+
+#[cfg(feature = "std")]
+impl Clock for SystemClock {
+	type Instant = std::time::Instant;
+
+	#[inline(always)]
+	fn now(&self) -> std::time::Instant {
+		std::time::Instant::now()
+	}
+
+	fn saturating_add(
+		&self,
+		instant: std::time::Instant,
+		duration: Duration,
+	) -> std::time::Instant {
+		if let Some(deadline) = instant.checked_add(duration) {
+			return deadline;
+		}
+
+		// `Instant` has no maximum value: binary search the latest
+		// representable instant, `instant + low` fitting and
+		// `instant + high` overflowing.
+		let mut low = Duration::ZERO;
+		let mut high = duration;
+		while high - low > Duration::from_nanos(1) {
+			let middle = low + (high - low) / 2;
+			match instant.checked_add(middle) {
+				Some(_) => low = middle,
+				None => high = middle,
+			}
+		}
+
+		instant + low
+	}
+}
+
+/// Clock advanced manually, measuring the time elapsed since its creation.
+///
+/// This is mostly useful for testing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct ManualClock {
+	now: Cell<Duration>,
+}
+
+impl ManualClock {
+	/// Creates a new clock at instant zero.
+	#[inline(always)]
+	pub fn new() -> Self {
+		Self::default()
+	}
+
+	/// Advances the clock by the given duration.
+	#[inline(always)]
+	pub fn advance(&self, duration: Duration) {
+		self.now.set(self.now.get() + duration)
+	}
+}
+
+impl Clock for ManualClock {
+	type Instant = Duration;
+
+	#[inline(always)]
+	fn now(&self) -> Duration {
+		self.now.get()
+	}
+
+	#[inline(always)]
+	fn saturating_add(&self, instant: Duration, duration: Duration) -> Duration {
+		instant.saturating_add(duration)
+	}
+}
+
+/// Entry of the inner map of an [`Expiring`] map,
+/// storing a value with its expiration instant.
+///
+/// This is implemented by pairs `(V, I)`.
+pub trait Entry {
+	/// Value type.
+	type Value;
+
+	/// Expiration instant type.
+	type Instant: Copy + Ord;
+
+	/// Creates a new entry.
+	fn new(value: Self::Value, deadline: Self::Instant) -> Self;
+
+	/// Returns a reference to the value and the expiration instant.
+	fn parts(&self) -> (&Self::Value, Self::Instant);
+
+	/// Returns a mutable reference to the value and the expiration instant.
+	fn parts_mut(&mut self) -> (&mut Self::Value, Self::Instant);
+
+	/// Returns the value and the expiration instant.
+	fn into_parts(self) -> (Self::Value, Self::Instant);
+}
+
+impl<V, I: Copy + Ord> Entry for (V, I) {
+	type Value = V;
+	type Instant = I;
+
+	#[inline(always)]
+	fn new(value: V, deadline: I) -> Self {
+		(value, deadline)
+	}
+
+	#[inline(always)]
+	fn parts(&self) -> (&V, I) {
+		(&self.0, self.1)
+	}
+
+	#[inline(always)]
+	fn parts_mut(&mut self) -> (&mut V, I) {
+		(&mut self.0, self.1)
+	}
+
+	#[inline(always)]
+	fn into_parts(self) -> (V, I) {
+		self
+	}
+}
+
+/// Value type of an [`Expiring`] map backed by `M`.
+type Value<M> = <<M as Collection>::Item as Entry>::Value;
+
+/// Map whose entries expire after a time-to-live.
+///
+/// The entries are stored in any map `M` from keys to pairs of a value and
+/// its expiration instant given by the clock `Clk`
+/// (such as a [`MapMut<K, (V, Clk::Instant)>`](crate::MapMut)).
+/// Entries inserted with [`MapInsert`] expire after the default time-to-live
+/// of the map, and [`Expiring::insert_with_ttl`] can be used to choose the
+/// time-to-live of an entry.
+///
+/// Expired entries are treated as absent by every operation, so that the map
+/// is a [`MapMut<K, V>`](crate::MapMut), but they still use memory until
+/// they are removed with [`Expiring::purge_expired`].
+/// Since expired entries must be skipped, [`Len`] is computed by iterating
+/// over the entries.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{expiring::{Expiring, ManualClock}, Get, Len, MapInsert};
+/// use std::{collections::HashMap, time::Duration};
+///
+/// let clock = ManualClock::new();
+/// let mut map: Expiring<HashMap<&str, (i32, Duration)>, _> =
+///   Expiring::new(HashMap::new(), &clock, Duration::from_secs(10));
+/// map.insert("a", 1);
+/// map.insert_with_ttl("b", 2, Duration::from_secs(30));
+///
+/// clock.advance(Duration::from_secs(20));
+/// assert_eq!(map.get(&"a"), None);
+/// assert_eq!(map.get(&"b"), Some(&2));
+/// assert_eq!(map.len(), 1);
+///
+/// assert_eq!(map.purge_expired(), 1);
+/// assert_eq!(map.inner().len(), 1);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Expiring<M, Clk> {
+	map: M,
+	clock: Clk,
+	ttl: Duration,
+}
+
+impl<M, Clk> Expiring<M, Clk> {
+	/// Creates a new expiring map from the given map of entries,
+	/// with the given clock and default time-to-live.
+	#[inline(always)]
+	pub fn new(map: M, clock: Clk, ttl: Duration) -> Self {
+		Self { map, clock, ttl }
+	}
+
+	/// Returns a reference to the inner map, including the expired entries.
+	#[inline(always)]
+	pub fn inner(&self) -> &M {
+		&self.map
+	}
+
+	/// Returns a reference to the clock.
+	#[inline(always)]
+	pub fn clock(&self) -> &Clk {
+		&self.clock
+	}
+
+	/// Returns the default time-to-live of the entries.
+	#[inline(always)]
+	pub fn ttl(&self) -> Duration {
+		self.ttl
+	}
+
+	/// Sets the default time-to-live of the entries inserted from now on.
+	#[inline(always)]
+	pub fn set_ttl(&mut self, ttl: Duration) {
+		self.ttl = ttl
+	}
+
+	/// Consumes the wrapper and returns the inner map,
+	/// including the expired entries.
+	#[inline(always)]
+	pub fn into_inner(self) -> M {
+		self.map
+	}
+
+	/// Inserts the given entry, expiring after the given time-to-live.
+	///
+	/// If the expiration instant overflows, it saturates at the latest
+	/// instant the clock can represent, so that the entry never expires
+	/// in practice.
+	///
+	/// Returns the previous value of the key, unless it has expired.
+	pub fn insert_with_ttl<K>(&mut self, key: K, value: Value<M>, ttl: Duration) -> Option<Value<M>>
+	where
+		Clk: Clock,
+		M: MapInsert<K, Output = Option<<M as Collection>::Item>>,
+		M::Item: Entry<Instant = Clk::Instant>,
+	{
+		let now = self.clock.now();
+		let (value, deadline) = self
+			.map
+			.insert(key, Entry::new(value, self.clock.saturating_add(now, ttl)))?
+			.into_parts();
+		if deadline > now {
+			Some(value)
+		} else {
+			None
+		}
+	}
+
+	/// Removes every expired entry.
+	///
+	/// Returns the number of removed entries.
+	pub fn purge_expired(&mut self) -> usize
+	where
+		Clk: Clock,
+		M: MapIter + for<'a> Remove<&'a <M as Keyed>::Key>,
+		M::Key: Clone,
+		M::Item: Entry<Instant = Clk::Instant>,
+	{
+		let now = self.clock.now();
+		let expired: Vec<M::Key> = self
+			.map
+			.iter()
+			.filter(|(_, entry)| entry.parts().1 <= now)
+			.map(|(key, _)| (*key).clone())
+			.collect();
+
+		for key in &expired {
+			self.map.remove(key);
+		}
+
+		expired.len()
+	}
+
+	/// Returns an iterator over the entries that have not expired.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, M, Clk>
+	where
+		Clk: Clock,
+		M: MapIter + SimpleCollectionRef,
+		M::Item: Entry<Instant = Clk::Instant>,
+	{
+		Iter {
+			entries: self.map.iter(),
+			now: self.clock.now(),
+		}
+	}
+
+	/// Checks that the given expiration instant has not been reached.
+	#[inline(always)]
+	fn is_live(&self, deadline: Clk::Instant) -> bool
+	where
+		Clk: Clock,
+	{
+		deadline > self.clock.now()
+	}
+}
+
+impl<M, Clk: Clock> Collection for Expiring<M, Clk>
+where
+	M: Collection,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type Item = Value<M>;
+}
+
+impl<M, Clk: Clock> CollectionRef for Expiring<M, Clk>
+where
+	M: Collection,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type ItemRef<'a> = &'a Value<M> where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<M, Clk: Clock> SimpleCollectionRef for Expiring<M, Clk>
+where
+	M: Collection,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	crate::simple_collection_ref!();
+}
+
+impl<M, Clk: Clock> CollectionMut for Expiring<M, Clk>
+where
+	M: Collection,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type ItemMut<'a> = &'a mut Value<M> where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<M, Clk: Clock> SimpleCollectionMut for Expiring<M, Clk>
+where
+	M: Collection,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	crate::simple_collection_mut!();
+}
+
+impl<M, Clk: Clock> Keyed for Expiring<M, Clk>
+where
+	M: Keyed,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type Key = M::Key;
+}
+
+impl<M, Clk: Clock> KeyedRef for Expiring<M, Clk>
+where
+	M: KeyedRef,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type KeyRef<'a> = M::KeyRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_key_ref(r)
+	}
+}
+
+impl<M, Clk: Clock> Len for Expiring<M, Clk>
+where
+	M: MapIter + SimpleCollectionRef,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	/// Returns the number of entries that have not expired.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.iter().count()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.iter().next().is_none()
+	}
+}
+
+impl<'a, Q: ?Sized, M, Clk: Clock> Get<&'a Q> for Expiring<M, Clk>
+where
+	M: Get<&'a Q> + SimpleCollectionRef,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&Value<M>> {
+		let (value, deadline) = M::into_ref(self.map.get(key)?).parts();
+		if self.is_live(deadline) {
+			Some(value)
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, Q: ?Sized, M, Clk: Clock> GetMut<&'a Q> for Expiring<M, Clk>
+where
+	M: Get<&'a Q> + GetMut<&'a Q> + SimpleCollectionRef + SimpleCollectionMut,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut Value<M>> {
+		let now = self.clock.now();
+		let (value, deadline) = M::into_mut(self.map.get_mut(key)?).parts_mut();
+		if deadline > now {
+			Some(value)
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, Q: ?Sized, M, Clk: Clock> GetKeyValue<&'a Q> for Expiring<M, Clk>
+where
+	M: GetKeyValue<&'a Q> + SimpleCollectionRef + KeyedRef,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(M::KeyRef<'_>, &Value<M>)> {
+		let (key, entry) = self.map.get_key_value(key)?;
+		let (value, deadline) = M::into_ref(entry).parts();
+		if self.is_live(deadline) {
+			Some((key, value))
+		} else {
+			None
+		}
+	}
+}
+
+impl<K, M, Clk: Clock> MapInsert<K> for Expiring<M, Clk>
+where
+	M: MapInsert<K, Output = Option<<M as Collection>::Item>>,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type Output = Option<Value<M>>;
+
+	/// Inserts the given entry, expiring after the default time-to-live.
+	///
+	/// Returns the previous value of the key, unless it has expired.
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: Value<M>) -> Option<Value<M>> {
+		self.insert_with_ttl(key, value, self.ttl)
+	}
+}
+
+impl<'a, Q: ?Sized, M, Clk: Clock> Remove<&'a Q> for Expiring<M, Clk>
+where
+	M: Remove<&'a Q>,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	/// Removes the given key, returning its value unless it has expired.
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<Value<M>> {
+		let (value, deadline) = self.map.remove(key)?.into_parts();
+		if self.is_live(deadline) {
+			Some(value)
+		} else {
+			None
+		}
+	}
+}
+
+impl<M: Clear, Clk> Clear for Expiring<M, Clk> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.map.clear()
+	}
+}
+
+impl<M, Clk: Clock> MapIter for Expiring<M, Clk>
+where
+	M: MapIter + SimpleCollectionRef,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type Iter<'a> = Iter<'a, M, Clk> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+/// Iterator over the entries of an [`Expiring`] map that have not expired.
+pub struct Iter<'a, M: MapIter + 'a, Clk: Clock> {
+	entries: M::Iter<'a>,
+	now: Clk::Instant,
+}
+
+impl<'a, M, Clk: Clock> Iterator for Iter<'a, M, Clk>
+where
+	M: MapIter + SimpleCollectionRef + 'a,
+	M::Item: Entry<Instant = Clk::Instant>,
+{
+	type Item = (M::KeyRef<'a>, &'a Value<M>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		for (key, entry) in self.entries.by_ref() {
+			let (value, deadline) = M::into_ref(entry).parts();
+			if deadline > self.now {
+				return Some((key, value));
+			}
+		}
+
+		None
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Expiring, ManualClock, SystemClock};
+	use crate::{Get, Len, MapInsert, Remove};
+	use std::{collections::HashMap, time::Duration};
+
+	#[test]
+	fn expires_at_the_exact_deadline() {
+		let clock = ManualClock::new();
+		let mut map: Expiring<HashMap<&str, (i32, Duration)>, _> =
+			Expiring::new(HashMap::new(), &clock, Duration::from_secs(10));
+		map.insert("a", 1);
+
+		clock.advance(Duration::from_secs(9));
+		assert_eq!(map.get(&"a"), Some(&1));
+
+		clock.advance(Duration::from_secs(1));
+		assert_eq!(map.get(&"a"), None);
+		assert_eq!(map.len(), 0);
+		assert_eq!(map.inner().len(), 1);
+		assert_eq!(map.purge_expired(), 1);
+		assert_eq!(map.inner().len(), 0);
+	}
+
+	#[test]
+	fn expired_entries_are_absent() {
+		let clock = ManualClock::new();
+		let mut map: Expiring<HashMap<&str, (i32, Duration)>, _> =
+			Expiring::new(HashMap::new(), &clock, Duration::from_secs(1));
+		map.insert("a", 1);
+		clock.advance(Duration::from_secs(1));
+
+		assert_eq!(map.insert("a", 2), None);
+		assert_eq!(map.remove(&"a"), Some(2));
+		map.insert("b", 3);
+		clock.advance(Duration::from_secs(1));
+		assert_eq!(map.remove(&"b"), None);
+	}
+
+	#[test]
+	fn zero_ttl_expires_immediately() {
+		let clock = ManualClock::new();
+		let mut map: Expiring<HashMap<&str, (i32, Duration)>, _> =
+			Expiring::new(HashMap::new(), &clock, Duration::ZERO);
+		map.insert("a", 1);
+		assert_eq!(map.get(&"a"), None);
+	}
+
+	#[test]
+	fn overflowing_ttl_saturates() {
+		let clock = ManualClock::new();
+		clock.advance(Duration::from_secs(1));
+		let mut map: Expiring<HashMap<&str, (i32, Duration)>, _> =
+			Expiring::new(HashMap::new(), &clock, Duration::MAX);
+		map.insert("a", 1);
+		assert_eq!(map.inner().get(&"a"), Some(&(1, Duration::MAX)));
+
+		clock.advance(Duration::from_secs(1_000_000));
+		assert_eq!(map.get(&"a"), Some(&1));
+	}
+
+	#[test]
+	fn system_clock_ttl() {
+		let mut map: Expiring<HashMap<&str, (i32, std::time::Instant)>, _> =
+			Expiring::new(HashMap::new(), SystemClock, Duration::from_secs(5));
+		map.insert("a", 1);
+		map.insert_with_ttl("b", 2, Duration::from_secs(3));
+		assert_eq!(map.get(&"a"), Some(&1));
+		assert_eq!(map.get(&"b"), Some(&2));
+		assert_eq!(map.len(), 2);
+	}
+
+	#[test]
+	fn overflowing_ttl_saturates_with_the_system_clock() {
+		let mut map: Expiring<HashMap<&str, (i32, std::time::Instant)>, _> =
+			Expiring::new(HashMap::new(), SystemClock, Duration::from_secs(10));
+		map.insert_with_ttl("a", 1, Duration::MAX);
+		assert_eq!(map.get(&"a"), Some(&1));
+		assert_eq!(map.len(), 1);
+	}
+}
@@ -1,7 +1,7 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut,
-	SimpleCollectionRef, SimpleKeyedRef,
+	Append, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
+	KeyedRef, Len, MapInsert, MapInsertAll, MapIter, MapIterMut, Remove, SimpleCollectionMut,
+	SimpleCollectionRef, SimpleKeyedRef, SplitOff,
 };
 use alloc::collections::BTreeMap;
 use core::borrow::Borrow;
@@ -98,6 +98,13 @@ impl<K: Ord, V> MapInsert<K> for BTreeMap<K, V> {
 	}
 }
 
+impl<K: Ord, V> MapInsertAll<K> for BTreeMap<K, V> {
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (K, V)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
 impl<'a, Q, K: Ord, V> Remove<&'a Q> for BTreeMap<K, V>
 where
 	K: Borrow<Q>,
@@ -116,6 +123,24 @@ impl<K: Ord, V> Clear for BTreeMap<K, V> {
 	}
 }
 
+impl<K: Ord, V> Append for BTreeMap<K, V> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<Q, K: Ord, V> SplitOff<&Q> for BTreeMap<K, V>
+where
+	K: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn split_off(&mut self, key: &Q) -> Self {
+		self.split_off(key)
+	}
+}
+
 impl<K, V> Iter for BTreeMap<K, V> {
 	type Iter<'a> = alloc::collections::btree_map::Values<'a, K, V> where Self: 'a;
 
@@ -1,9 +1,9 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, Insert, Iter, Len, Remove,
-	SimpleCollectionMut, SimpleCollectionRef,
+	Append, Clear, Collection, CollectionMut, CollectionRef, Get, Insert, InsertAll, Iter, Len,
+	OrderedRange, Remove, SimpleCollectionMut, SimpleCollectionRef, SplitOff,
 };
 use alloc::collections::BTreeSet;
-use core::borrow::Borrow;
+use core::{borrow::Borrow, ops::RangeBounds};
 
 impl<T> Collection for BTreeSet<T> {
 	type Item = T;
@@ -61,6 +61,13 @@ impl<T: Ord> Insert for BTreeSet<T> {
 	}
 }
 
+impl<T: Ord> InsertAll for BTreeSet<T> {
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = T>>(&mut self, elements: I) {
+		self.extend(elements)
+	}
+}
+
 impl<'a, Q, T: Ord> Remove<&'a Q> for BTreeSet<T>
 where
 	T: Borrow<Q>,
@@ -79,6 +86,24 @@ impl<T: Ord> Clear for BTreeSet<T> {
 	}
 }
 
+impl<T: Ord> Append for BTreeSet<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<Q, T: Ord> SplitOff<&Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	#[inline(always)]
+	fn split_off(&mut self, key: &Q) -> Self {
+		self.split_off(key)
+	}
+}
+
 impl<T> Iter for BTreeSet<T> {
 	type Iter<'a> = alloc::collections::btree_set::Iter<'a, T> where Self: 'a;
 
@@ -87,3 +112,16 @@ impl<T> Iter for BTreeSet<T> {
 		self.iter()
 	}
 }
+
+impl<Q, T: Ord> OrderedRange<Q> for BTreeSet<T>
+where
+	T: Borrow<Q>,
+	Q: Ord + ?Sized,
+{
+	type Range<'a> = alloc::collections::btree_set::Range<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn range<R: RangeBounds<Q>>(&self, range: R) -> Self::Range<'_> {
+		self.range(range)
+	}
+}
@@ -1,8 +1,10 @@
 use crate::{
-	Back, BackMut, Capacity, Clear, Collection, CollectionMut, CollectionRef, Front, FrontMut, Len,
-	PopBack, PushBack, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut,
+	Len, PopBack, PopFront, PushBack, PushBackAll, PushFront, Remove, Reserve, Reverse, Rotate,
+	SimpleCollectionMut, SimpleCollectionRef, Sort, SplitOff, Swap, WithCapacity,
 };
 use alloc::collections::VecDeque;
+use core::cmp::Ordering;
 
 impl<T> Collection for VecDeque<T> {
 	type Item = T;
@@ -61,40 +63,38 @@ impl<T> Reserve for VecDeque<T> {
 	}
 }
 
-impl<T> Front for VecDeque<T> {
+impl<T> Get<usize> for VecDeque<T> {
 	#[inline(always)]
-	fn front(&self) -> Option<&T> {
-		self.front()
+	fn get(&self, index: usize) -> Option<&T> {
+		self.get(index)
 	}
 }
 
-impl<T> FrontMut for VecDeque<T> {
+impl<T> GetMut<usize> for VecDeque<T> {
 	#[inline(always)]
-	fn front_mut(&mut self) -> Option<&mut T> {
-		self.front_mut()
+	fn get_mut(&mut self, index: usize) -> Option<&mut T> {
+		self.get_mut(index)
 	}
 }
 
-impl<T> Back for VecDeque<T> {
+impl<T> PushBack for VecDeque<T> {
+	type Output = ();
+
 	#[inline(always)]
-	fn back(&self) -> Option<&T> {
-		self.back()
+	fn push_back(&mut self, t: T) {
+		self.push_back(t)
 	}
 }
 
-impl<T> BackMut for VecDeque<T> {
+impl<T> PushBackAll for VecDeque<T> {
 	#[inline(always)]
-	fn back_mut(&mut self) -> Option<&mut T> {
-		self.back_mut()
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
 	}
-}
-
-impl<T> PushBack for VecDeque<T> {
-	type Output = ();
 
 	#[inline(always)]
-	fn push_back(&mut self, t: T) {
-		self.push_back(t)
+	fn push_back_all<I: IntoIterator<Item = T>>(&mut self, elements: I) {
+		self.extend(elements)
 	}
 }
 
@@ -105,9 +105,114 @@ impl<T> PopBack for VecDeque<T> {
 	}
 }
 
+impl<T> PushFront for VecDeque<T> {
+	type Output = ();
+
+	#[inline(always)]
+	fn push_front(&mut self, t: T) {
+		self.push_front(t)
+	}
+}
+
+impl<T> PopFront for VecDeque<T> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<T> {
+		self.pop_front()
+	}
+}
+
+impl<T> Remove<usize> for VecDeque<T> {
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<T> {
+		self.remove(index)
+	}
+}
+
 impl<T> Clear for VecDeque<T> {
 	#[inline(always)]
 	fn clear(&mut self) {
 		self.clear()
 	}
 }
+
+impl<T> Append for VecDeque<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T> SplitOff<usize> for VecDeque<T> {
+	#[inline(always)]
+	fn split_off(&mut self, key: usize) -> Self {
+		self.split_off(key)
+	}
+}
+
+impl<T> Swap for VecDeque<T> {
+	#[inline(always)]
+	fn swap(&mut self, i: usize, j: usize) {
+		self.swap(i, j)
+	}
+}
+
+impl<T> Reverse for VecDeque<T> {}
+
+impl<T> Rotate for VecDeque<T> {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.rotate_right(k)
+	}
+}
+
+impl<T> Sort for VecDeque<T> {
+	/// Rearranges the internal storage of the deque so that it is
+	/// contiguous, then sorts it.
+	#[inline(always)]
+	fn sort(&mut self)
+	where
+		T: Ord,
+	{
+		self.make_contiguous().sort()
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.make_contiguous().sort_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.make_contiguous().sort_unstable_by_key(f)
+	}
+}
+
+impl<T> Iter for VecDeque<T> {
+	type Iter<'a> = alloc::collections::vec_deque::Iter<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<T> IterMut for VecDeque<T> {
+	type IterMut<'a> = alloc::collections::vec_deque::IterMut<'a, T> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
@@ -1,6 +1,7 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, ContiguousMut, Get, GetMut,
+	Iter, IterMut, Len, PopBack, PushBack, PushBackAll, Remove, Reserve, SimpleCollectionMut,
+	SimpleCollectionRef, SplitOff, WithCapacity,
 };
 use alloc::vec::Vec;
 
@@ -84,6 +85,26 @@ impl<T> PushBack for Vec<T> {
 	}
 }
 
+impl<T> PushBackAll for Vec<T> {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn push_back_all<I: IntoIterator<Item = T>>(&mut self, elements: I) {
+		self.extend(elements)
+	}
+
+	#[inline(always)]
+	fn push_back_slice(&mut self, elements: &[T])
+	where
+		T: Clone,
+	{
+		self.extend_from_slice(elements)
+	}
+}
+
 impl<T> PopBack for Vec<T> {
 	#[inline(always)]
 	fn pop_back(&mut self) -> Option<T> {
@@ -109,6 +130,27 @@ impl<T> Clear for Vec<T> {
 	}
 }
 
+impl<T> Append for Vec<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl<T> SplitOff<usize> for Vec<T> {
+	#[inline(always)]
+	fn split_off(&mut self, key: usize) -> Self {
+		self.split_off(key)
+	}
+}
+
+impl<T> ContiguousMut for Vec<T> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [T] {
+		self
+	}
+}
+
 impl<T> Iter for Vec<T> {
 	type Iter<'a> = core::slice::Iter<'a, T> where Self: 'a;
 
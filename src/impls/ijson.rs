@@ -1,8 +1,11 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut,
-	GetMut, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack,
-	Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
+	json::{JsonValue, ValueKind, Values},
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, ContiguousMut, Get,
+	GetKeyValue, GetKeyValueMut, GetMut, Iter, IterMut, Keyed, KeyedRef, Len, MapInsert,
+	MapInsertAll, MapIter, MapIterMut, PopBack, PushBack, PushBackAll, Remove, Reserve,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, SplitOff, WithCapacity,
 };
+use alloc::string::String;
 use ijson::{IArray, IObject, IString, IValue};
 
 impl Collection for IObject {
@@ -55,6 +58,24 @@ impl Len for IObject {
 	}
 }
 
+impl WithCapacity for IObject {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl Iter for IObject {
+	#[allow(clippy::type_complexity)]
+	type Iter<'a> =
+		core::iter::Map<ijson::object::Iter<'a>, fn((&'a IString, &'a IValue)) -> &'a IValue>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter().map(|(_, value)| value)
+	}
+}
+
 impl MapIter for IObject {
 	type Iter<'a> = ijson::object::Iter<'a>;
 
@@ -73,31 +94,83 @@ impl MapIterMut for IObject {
 	}
 }
 
-impl<Q: ijson::object::ObjectIndex> Get<Q> for IObject {
+/// Type that can be used to look up the members of an [`IObject`].
+///
+/// Implemented by `str`, `String` and `IString`, so that `IObject` can be
+/// accessed by reference to any of them, and by references to those types,
+/// so that the lookups by `&&str` or `&&IString` supported by `ijson` are
+/// still available.
+pub trait ObjectKey {
+	/// Index type used by `ijson`.
+	type Index<'a>: ijson::object::ObjectIndex
+	where
+		Self: 'a;
+
+	/// Returns the index used by `ijson` to look up this key.
+	fn index(&self) -> Self::Index<'_>;
+}
+
+impl ObjectKey for str {
+	type Index<'a> = &'a str;
+
 	#[inline(always)]
-	fn get(&self, q: Q) -> Option<&IValue> {
-		self.get(q)
+	fn index(&self) -> &str {
+		self
 	}
 }
 
-impl<Q: ijson::object::ObjectIndex> GetKeyValue<Q> for IObject {
+impl ObjectKey for String {
+	type Index<'a> = &'a str;
+
 	#[inline(always)]
-	fn get_key_value(&self, q: Q) -> Option<(&IString, &IValue)> {
-		self.get_key_value(q)
+	fn index(&self) -> &str {
+		self
 	}
 }
 
-impl<Q: ijson::object::ObjectIndex> GetMut<Q> for IObject {
+impl ObjectKey for IString {
+	type Index<'a> = &'a IString;
+
 	#[inline(always)]
-	fn get_mut(&mut self, q: Q) -> Option<&mut IValue> {
-		self.get_mut(q)
+	fn index(&self) -> &IString {
+		self
 	}
 }
 
-impl<Q: ijson::object::ObjectIndex> GetKeyValueMut<Q> for IObject {
+impl<T: ?Sized + ObjectKey> ObjectKey for &T {
+	type Index<'a> = T::Index<'a> where Self: 'a;
+
 	#[inline(always)]
-	fn get_key_value_mut(&mut self, q: Q) -> Option<(&IString, &mut IValue)> {
-		self.get_key_value_mut(q)
+	fn index(&self) -> T::Index<'_> {
+		(**self).index()
+	}
+}
+
+impl<'a, Q: ?Sized + ObjectKey> Get<&'a Q> for IObject {
+	#[inline(always)]
+	fn get(&self, q: &'a Q) -> Option<&IValue> {
+		self.get(q.index())
+	}
+}
+
+impl<'a, Q: ?Sized + ObjectKey> GetKeyValue<&'a Q> for IObject {
+	#[inline(always)]
+	fn get_key_value(&self, q: &'a Q) -> Option<(&IString, &IValue)> {
+		self.get_key_value(q.index())
+	}
+}
+
+impl<'a, Q: ?Sized + ObjectKey> GetMut<&'a Q> for IObject {
+	#[inline(always)]
+	fn get_mut(&mut self, q: &'a Q) -> Option<&mut IValue> {
+		self.get_mut(q.index())
+	}
+}
+
+impl<'a, Q: ?Sized + ObjectKey> GetKeyValueMut<&'a Q> for IObject {
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, q: &'a Q) -> Option<(&IString, &mut IValue)> {
+		self.get_key_value_mut(q.index())
 	}
 }
 
@@ -110,10 +183,17 @@ impl MapInsert<IString> for IObject {
 	}
 }
 
-impl<Q: ijson::object::ObjectIndex> Remove<Q> for IObject {
+impl MapInsertAll<IString> for IObject {
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (IString, IValue)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
+impl<'a, Q: ?Sized + ObjectKey> Remove<&'a Q> for IObject {
 	#[inline(always)]
-	fn remove(&mut self, key: Q) -> Option<IValue> {
-		self.remove(key)
+	fn remove(&mut self, key: &'a Q) -> Option<IValue> {
+		self.remove(key.index())
 	}
 }
 
@@ -124,6 +204,13 @@ impl Clear for IObject {
 	}
 }
 
+impl Append for IObject {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(core::mem::take(other))
+	}
+}
+
 impl Collection for IArray {
 	type Item = IValue;
 }
@@ -140,6 +227,14 @@ impl CollectionMut for IArray {
 	crate::covariant_item_mut!();
 }
 
+impl SimpleCollectionRef for IArray {
+	crate::simple_collection_ref!();
+}
+
+impl SimpleCollectionMut for IArray {
+	crate::simple_collection_mut!();
+}
+
 impl WithCapacity for IArray {
 	#[inline(always)]
 	fn with_capacity(capacity: usize) -> Self {
@@ -196,6 +291,18 @@ impl PushBack for IArray {
 	}
 }
 
+impl PushBackAll for IArray {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn push_back_all<I: IntoIterator<Item = IValue>>(&mut self, elements: I) {
+		self.extend(elements)
+	}
+}
+
 impl PopBack for IArray {
 	#[inline(always)]
 	fn pop_back(&mut self) -> Option<IValue> {
@@ -221,8 +328,40 @@ impl Clear for IArray {
 	}
 }
 
+impl Append for IArray {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(core::mem::take(other))
+	}
+}
+
+impl SplitOff<usize> for IArray {
+	/// Moves the items at and after `key` into a new array.
+	///
+	/// Unlike [`Vec::split_off`](alloc::vec::Vec::split_off), which copies
+	/// them at once, the items are taken out one at a time since `ijson` has
+	/// no native split.
+	fn split_off(&mut self, key: usize) -> Self {
+		assert!(key <= self.len(), "`key` out of bounds");
+		let mut result = IArray::with_capacity(self.len() - key);
+		for value in &mut self.as_mut_slice()[key..] {
+			result.push(core::mem::take(value))
+		}
+
+		self.truncate(key);
+		result
+	}
+}
+
+impl ContiguousMut for IArray {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [IValue] {
+		self.as_mut_slice()
+	}
+}
+
 impl Iter for IArray {
-	type Iter<'a> = std::slice::Iter<'a, IValue>;
+	type Iter<'a> = core::slice::Iter<'a, IValue>;
 
 	#[inline(always)]
 	fn iter(&self) -> Self::Iter<'_> {
@@ -231,10 +370,146 @@ impl Iter for IArray {
 }
 
 impl IterMut for IArray {
-	type IterMut<'a> = std::slice::IterMut<'a, IValue>;
+	type IterMut<'a> = core::slice::IterMut<'a, IValue>;
 
 	#[inline(always)]
 	fn iter_mut(&mut self) -> Self::IterMut<'_> {
 		self.as_mut_slice().iter_mut()
 	}
 }
+
+impl JsonValue for IValue {
+	type Key = IString;
+	type Object = IObject;
+	type Array = IArray;
+
+	#[inline(always)]
+	fn null() -> Self {
+		IValue::NULL
+	}
+
+	#[inline(always)]
+	fn kind(&self) -> ValueKind {
+		match self.type_() {
+			ijson::ValueType::Null => ValueKind::Null,
+			ijson::ValueType::Bool => ValueKind::Bool,
+			ijson::ValueType::Number => ValueKind::Number,
+			ijson::ValueType::String => ValueKind::String,
+			ijson::ValueType::Array => ValueKind::Array,
+			ijson::ValueType::Object => ValueKind::Object,
+		}
+	}
+
+	#[inline(always)]
+	fn is_null(&self) -> bool {
+		self.is_null()
+	}
+
+	#[inline(always)]
+	fn as_str(&self) -> Option<&str> {
+		self.as_string().map(|s| s.as_str())
+	}
+
+	#[inline(always)]
+	fn as_object(&self) -> Option<&IObject> {
+		self.as_object()
+	}
+
+	#[inline(always)]
+	fn as_object_mut(&mut self) -> Option<&mut IObject> {
+		self.as_object_mut()
+	}
+
+	#[inline(always)]
+	fn as_array(&self) -> Option<&IArray> {
+		self.as_array()
+	}
+
+	#[inline(always)]
+	fn as_array_mut(&mut self) -> Option<&mut IArray> {
+		self.as_array_mut()
+	}
+
+	#[inline(always)]
+	fn array_insert(array: &mut IArray, index: usize, item: IValue) {
+		array.insert(index, item)
+	}
+}
+
+impl Collection for IValue {
+	type Item = IValue;
+}
+
+impl CollectionRef for IValue {
+	type ItemRef<'a> = &'a IValue where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl CollectionMut for IValue {
+	type ItemMut<'a> = &'a mut IValue where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl SimpleCollectionRef for IValue {
+	crate::simple_collection_ref!();
+}
+
+impl SimpleCollectionMut for IValue {
+	crate::simple_collection_mut!();
+}
+
+impl Len for IValue {
+	/// Returns the number of items of an array or members of an object,
+	/// or `0` for any other value.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		match (JsonValue::as_object(self), JsonValue::as_array(self)) {
+			(Some(object), _) => Len::len(object),
+			(_, Some(array)) => Len::len(array),
+			_ => 0,
+		}
+	}
+}
+
+impl<'a> Get<&'a str> for IValue {
+	/// Returns the given member of an object, or `None` for any other value.
+	#[inline(always)]
+	fn get(&self, key: &'a str) -> Option<&IValue> {
+		Get::get(JsonValue::as_object(self)?, key)
+	}
+}
+
+impl Get<usize> for IValue {
+	/// Returns the given item of an array, or `None` for any other value.
+	///
+	/// With [`Len`], this provides the [`Front`](crate::Front) and
+	/// [`Back`](crate::Back) implementations, which return `None` for any
+	/// value other than a non-empty array.
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&IValue> {
+		Get::get(JsonValue::as_array(self)?, index)
+	}
+}
+
+impl Iter for IValue {
+	type Iter<'a> = Values<'a, IValue>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl Clear for IValue {
+	/// Clears an array or object, leaving any other value unchanged.
+	#[inline(always)]
+	fn clear(&mut self) {
+		if let Some(object) = JsonValue::as_object_mut(self) {
+			Clear::clear(object)
+		} else if let Some(array) = JsonValue::as_array_mut(self) {
+			Clear::clear(array)
+		}
+	}
+}
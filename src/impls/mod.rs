@@ -14,4 +14,10 @@ mod smallvec;
 mod serde_json;
 
 #[cfg(feature = "ijson")]
-mod ijson;
+pub(crate) mod ijson;
+
+#[cfg(feature = "toml")]
+mod toml;
+
+#[cfg(feature = "serde_yaml")]
+mod serde_yaml;
@@ -1,9 +1,11 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed, KeyedRef,
-	Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut, SimpleCollectionRef,
-	SimpleKeyedRef,
+	json::{JsonValue, ValueKind, Values},
+	Append, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut,
+	GetMut, Iter, Keyed, KeyedRef, Len, MapInsert, MapInsertAll, MapIter, MapIterMut, Remove,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
 };
-use std::{borrow::Borrow, cmp::Ord, hash::Hash};
+use alloc::{string::String, vec::Vec};
+use core::{borrow::Borrow, cmp::Ord, hash::Hash};
 
 impl Collection for serde_json::Map<String, serde_json::Value> {
 	type Item = serde_json::Value;
@@ -60,6 +62,22 @@ impl Len for serde_json::Map<String, serde_json::Value> {
 	}
 }
 
+impl WithCapacity for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl Iter for serde_json::Map<String, serde_json::Value> {
+	type Iter<'a> = serde_json::map::Values<'a>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
 impl MapIter for serde_json::Map<String, serde_json::Value> {
 	type Iter<'a> = serde_json::map::Iter<'a> where Self: 'a;
 
@@ -112,6 +130,25 @@ where
 	}
 }
 
+impl<'a, Q: ?Sized> GetKeyValueMut<&'a Q> for serde_json::Map<String, serde_json::Value>
+where
+	String: Borrow<Q>,
+	Q: Ord + Hash,
+{
+	/// Returns the key-value pair of the given key, with a mutable value.
+	///
+	/// `serde_json` does not provide this operation, so the map is
+	/// searched linearly when it contains the key.
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, q: &'a Q) -> Option<(&String, &mut serde_json::Value)> {
+		if !self.contains_key(q) {
+			return None;
+		}
+
+		self.iter_mut().find(|(key, _)| (*key).borrow() == q)
+	}
+}
+
 impl MapInsert<String> for serde_json::Map<String, serde_json::Value> {
 	type Output = Option<serde_json::Value>;
 
@@ -121,6 +158,13 @@ impl MapInsert<String> for serde_json::Map<String, serde_json::Value> {
 	}
 }
 
+impl MapInsertAll<String> for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (String, serde_json::Value)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
 impl<'a, Q: ?Sized> Remove<&'a Q> for serde_json::Map<String, serde_json::Value>
 where
 	String: Borrow<Q>,
@@ -138,3 +182,146 @@ impl Clear for serde_json::Map<String, serde_json::Value> {
 		self.clear()
 	}
 }
+
+impl Append for serde_json::Map<String, serde_json::Value> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.append(other)
+	}
+}
+
+impl JsonValue for serde_json::Value {
+	type Key = String;
+	type Object = serde_json::Map<String, serde_json::Value>;
+	type Array = Vec<serde_json::Value>;
+
+	#[inline(always)]
+	fn null() -> Self {
+		serde_json::Value::Null
+	}
+
+	#[inline(always)]
+	fn kind(&self) -> ValueKind {
+		match self {
+			serde_json::Value::Null => ValueKind::Null,
+			serde_json::Value::Bool(_) => ValueKind::Bool,
+			serde_json::Value::Number(_) => ValueKind::Number,
+			serde_json::Value::String(_) => ValueKind::String,
+			serde_json::Value::Array(_) => ValueKind::Array,
+			serde_json::Value::Object(_) => ValueKind::Object,
+		}
+	}
+
+	#[inline(always)]
+	fn is_null(&self) -> bool {
+		self.is_null()
+	}
+
+	#[inline(always)]
+	fn as_str(&self) -> Option<&str> {
+		self.as_str()
+	}
+
+	#[inline(always)]
+	fn as_object(&self) -> Option<&serde_json::Map<String, serde_json::Value>> {
+		self.as_object()
+	}
+
+	#[inline(always)]
+	fn as_object_mut(&mut self) -> Option<&mut serde_json::Map<String, serde_json::Value>> {
+		self.as_object_mut()
+	}
+
+	#[inline(always)]
+	fn as_array(&self) -> Option<&Vec<serde_json::Value>> {
+		self.as_array()
+	}
+
+	#[inline(always)]
+	fn as_array_mut(&mut self) -> Option<&mut Vec<serde_json::Value>> {
+		self.as_array_mut()
+	}
+
+	#[inline(always)]
+	fn array_insert(array: &mut Vec<serde_json::Value>, index: usize, item: serde_json::Value) {
+		array.insert(index, item)
+	}
+}
+
+impl Collection for serde_json::Value {
+	type Item = serde_json::Value;
+}
+
+impl CollectionRef for serde_json::Value {
+	type ItemRef<'a> = &'a serde_json::Value where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl CollectionMut for serde_json::Value {
+	type ItemMut<'a> = &'a mut serde_json::Value where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl SimpleCollectionRef for serde_json::Value {
+	crate::simple_collection_ref!();
+}
+
+impl SimpleCollectionMut for serde_json::Value {
+	crate::simple_collection_mut!();
+}
+
+impl Len for serde_json::Value {
+	/// Returns the number of items of an array or members of an object,
+	/// or `0` for any other value.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		match (JsonValue::as_object(self), JsonValue::as_array(self)) {
+			(Some(object), _) => Len::len(object),
+			(_, Some(array)) => Len::len(array),
+			_ => 0,
+		}
+	}
+}
+
+impl<'a> Get<&'a str> for serde_json::Value {
+	/// Returns the given member of an object, or `None` for any other value.
+	#[inline(always)]
+	fn get(&self, key: &'a str) -> Option<&serde_json::Value> {
+		Get::get(JsonValue::as_object(self)?, key)
+	}
+}
+
+impl Get<usize> for serde_json::Value {
+	/// Returns the given item of an array, or `None` for any other value.
+	///
+	/// With [`Len`], this provides the [`Front`](crate::Front) and
+	/// [`Back`](crate::Back) implementations, which return `None` for any
+	/// value other than a non-empty array.
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&serde_json::Value> {
+		Get::get(JsonValue::as_array(self)?, index)
+	}
+}
+
+impl Iter for serde_json::Value {
+	type Iter<'a> = Values<'a, serde_json::Value>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl Clear for serde_json::Value {
+	/// Clears an array or object, leaving any other value unchanged.
+	#[inline(always)]
+	fn clear(&mut self) {
+		if let Some(object) = JsonValue::as_object_mut(self) {
+			Clear::clear(object)
+		} else if let Some(array) = JsonValue::as_array_mut(self) {
+			Clear::clear(array)
+		}
+	}
+}
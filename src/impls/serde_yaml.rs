@@ -0,0 +1,183 @@
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut,
+	GetMut, Iter, Keyed, KeyedRef, Len, MapInsert, MapInsertAll, MapIter, MapIterMut, Remove,
+	Reserve, SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
+};
+use serde_yaml::{mapping::Index, Mapping, Value};
+
+impl Collection for Mapping {
+	type Item = Value;
+}
+
+impl CollectionRef for Mapping {
+	type ItemRef<'a> = &'a Value where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl CollectionMut for Mapping {
+	type ItemMut<'a> = &'a mut Value where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl SimpleCollectionRef for Mapping {
+	crate::simple_collection_ref!();
+}
+
+impl SimpleCollectionMut for Mapping {
+	crate::simple_collection_mut!();
+}
+
+impl Keyed for Mapping {
+	type Key = Value;
+}
+
+impl KeyedRef for Mapping {
+	type KeyRef<'a> = &'a Value where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl SimpleKeyedRef for Mapping {
+	crate::simple_keyed_ref!();
+}
+
+impl Len for Mapping {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl WithCapacity for Mapping {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl Capacity for Mapping {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl Reserve for Mapping {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
+impl Iter for Mapping {
+	type Iter<'a> = serde_yaml::mapping::Values<'a>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl MapIter for Mapping {
+	type Iter<'a> = serde_yaml::mapping::Iter<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl MapIterMut for Mapping {
+	type IterMut<'a> = serde_yaml::mapping::IterMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<'a, Q: ?Sized + Index> Get<&'a Q> for Mapping {
+	#[inline(always)]
+	fn get(&self, q: &'a Q) -> Option<&Value> {
+		self.get(q)
+	}
+}
+
+impl<'a, Q: ?Sized + Index> GetMut<&'a Q> for Mapping {
+	#[inline(always)]
+	fn get_mut(&mut self, q: &'a Q) -> Option<&mut Value> {
+		self.get_mut(q)
+	}
+}
+
+impl<'a> GetKeyValue<&'a Value> for Mapping {
+	/// Returns the key-value pair of the given key.
+	///
+	/// `serde_yaml` does not provide this operation: a missing key is
+	/// detected with a hash lookup, but a present key is found by searching
+	/// the mapping linearly, in `O(n)` time.
+	fn get_key_value(&self, q: &'a Value) -> Option<(&Value, &Value)> {
+		if !self.contains_key(q) {
+			return None;
+		}
+
+		self.iter().find(|(key, _)| *key == q)
+	}
+}
+
+impl<'a> GetKeyValueMut<&'a Value> for Mapping {
+	/// Returns the key-value pair of the given key, with a mutable value.
+	///
+	/// `serde_yaml` does not provide this operation: a missing key is
+	/// detected with a hash lookup, but a present key is found by searching
+	/// the mapping linearly, in `O(n)` time.
+	fn get_key_value_mut(&mut self, q: &'a Value) -> Option<(&Value, &mut Value)> {
+		if !self.contains_key(q) {
+			return None;
+		}
+
+		self.iter_mut().find(|(key, _)| *key == q)
+	}
+}
+
+impl MapInsert<Value> for Mapping {
+	type Output = Option<Value>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: Value, value: Value) -> Option<Value> {
+		self.insert(key, value)
+	}
+}
+
+impl MapInsertAll<Value> for Mapping {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (Value, Value)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
+impl<'a, Q: ?Sized + Index> Remove<&'a Q> for Mapping {
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<Value> {
+		self.remove(key)
+	}
+}
+
+impl Clear for Mapping {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
@@ -1,6 +1,7 @@
 use crate::{
-	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetMut, Iter, IterMut, Len,
-	PopBack, PushBack, Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, ContiguousMut, Get, GetMut,
+	Iter, IterMut, Len, PopBack, PushBack, PushBackAll, Remove, Reserve, SimpleCollectionMut,
+	SimpleCollectionRef, SplitOff, WithCapacity,
 };
 use smallvec::{Array, SmallVec};
 
@@ -84,6 +85,26 @@ impl<A: Array> PushBack for SmallVec<A> {
 	}
 }
 
+impl<A: Array> PushBackAll for SmallVec<A> {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn push_back_all<I: IntoIterator<Item = A::Item>>(&mut self, elements: I) {
+		self.extend(elements)
+	}
+
+	#[inline(always)]
+	fn push_back_slice(&mut self, elements: &[A::Item])
+	where
+		A::Item: Clone,
+	{
+		self.insert_many(self.len(), elements.iter().cloned())
+	}
+}
+
 impl<A: Array> PopBack for SmallVec<A> {
 	#[inline(always)]
 	fn pop_back(&mut self) -> Option<A::Item> {
@@ -109,6 +130,31 @@ impl<A: Array> Clear for SmallVec<A> {
 	}
 }
 
+impl<A: Array> Append for SmallVec<A> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain(..))
+	}
+}
+
+impl<A: Array> SplitOff<usize> for SmallVec<A> {
+	/// Drains the items at and after `key` into a new vector.
+	///
+	/// `smallvec` does not provide this operation, so the items are moved
+	/// one by one instead of with the single copy of `Vec::split_off`.
+	#[inline(always)]
+	fn split_off(&mut self, key: usize) -> Self {
+		self.drain(key..).collect()
+	}
+}
+
+impl<A: Array> ContiguousMut for SmallVec<A> {
+	#[inline(always)]
+	fn as_mut_slice(&mut self) -> &mut [A::Item] {
+		self
+	}
+}
+
 impl<A: Array> Iter for SmallVec<A> {
 	type Iter<'a> = std::slice::Iter<'a, A::Item> where Self: 'a;
 
@@ -1,7 +1,7 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Iter, Keyed,
-	KeyedRef, Len, MapInsert, MapIter, MapIterMut, Remove, SimpleCollectionMut,
-	SimpleCollectionRef, SimpleKeyedRef,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut,
+	Iter, Keyed, KeyedRef, Len, MapInsert, MapInsertAll, MapIter, MapIterMut, Remove, Reserve,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
 };
 use std::{borrow::Borrow, collections::HashMap, hash::Hash};
 
@@ -43,6 +43,27 @@ impl<K, V> SimpleKeyedRef for HashMap<K, V> {
 	crate::simple_keyed_ref!();
 }
 
+impl<K, V> WithCapacity for HashMap<K, V> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl<K, V> Capacity for HashMap<K, V> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<K: Hash + Eq, V> Reserve for HashMap<K, V> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
 impl<K, V> Len for HashMap<K, V> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -97,6 +118,18 @@ impl<K: Hash + Eq, V> MapInsert<K> for HashMap<K, V> {
 	}
 }
 
+impl<K: Hash + Eq, V> MapInsertAll<K> for HashMap<K, V> {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (K, V)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
 impl<'a, Q, K: Hash + Eq, V> Remove<&'a Q> for HashMap<K, V>
 where
 	K: Borrow<Q>,
@@ -115,6 +148,13 @@ impl<K, V> Clear for HashMap<K, V> {
 	}
 }
 
+impl<K: Hash + Eq, V> Append for HashMap<K, V> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain())
+	}
+}
+
 impl<K, V> Iter for HashMap<K, V> {
 	type Iter<'a> = std::collections::hash_map::Values<'a, K, V> where Self: 'a;
 
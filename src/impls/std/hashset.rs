@@ -1,6 +1,6 @@
 use crate::{
-	Clear, Collection, CollectionMut, CollectionRef, Get, Insert, Iter, Len, Remove,
-	SimpleCollectionMut, SimpleCollectionRef,
+	Append, Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, Insert, InsertAll,
+	Iter, Len, Remove, Reserve, SimpleCollectionMut, SimpleCollectionRef, WithCapacity,
 };
 use std::{borrow::Borrow, collections::HashSet, hash::Hash};
 
@@ -28,6 +28,27 @@ impl<T> SimpleCollectionMut for HashSet<T> {
 	crate::simple_collection_mut!();
 }
 
+impl<T> WithCapacity for HashSet<T> {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl<T> Capacity for HashSet<T> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity()
+	}
+}
+
+impl<T: Hash + Eq> Reserve for HashSet<T> {
+	#[inline(always)]
+	fn reserve(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+}
+
 impl<T> Len for HashSet<T> {
 	#[inline(always)]
 	fn len(&self) -> usize {
@@ -59,6 +80,18 @@ impl<T: Hash + Eq> Insert for HashSet<T> {
 	}
 }
 
+impl<T: Hash + Eq> InsertAll for HashSet<T> {
+	#[inline(always)]
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserve(additional)
+	}
+
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = T>>(&mut self, elements: I) {
+		self.extend(elements)
+	}
+}
+
 impl<'a, Q, T: Hash + Eq> Remove<&'a Q> for HashSet<T>
 where
 	T: Borrow<Q>,
@@ -77,6 +110,13 @@ impl<T: Hash + Eq> Clear for HashSet<T> {
 	}
 }
 
+impl<T: Hash + Eq> Append for HashSet<T> {
+	#[inline(always)]
+	fn append(&mut self, other: &mut Self) {
+		self.extend(other.drain())
+	}
+}
+
 impl<T> Iter for HashSet<T> {
 	type Iter<'a> = std::collections::hash_set::Iter<'a, T> where Self: 'a;
 
@@ -0,0 +1,177 @@
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut, GetMut,
+	Iter, Keyed, KeyedRef, Len, MapInsert, MapInsertAll, MapIter, MapIterMut, Remove,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef, WithCapacity,
+};
+use alloc::string::String;
+use core::{borrow::Borrow, cmp::Ord, hash::Hash};
+
+impl Collection for toml::Table {
+	type Item = toml::Value;
+}
+
+impl CollectionRef for toml::Table {
+	type ItemRef<'a> = &'a toml::Value where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl CollectionMut for toml::Table {
+	type ItemMut<'a> = &'a mut toml::Value where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl SimpleCollectionRef for toml::Table {
+	crate::simple_collection_ref!();
+}
+
+impl SimpleCollectionMut for toml::Table {
+	crate::simple_collection_mut!();
+}
+
+impl Keyed for toml::Table {
+	type Key = String;
+}
+
+impl KeyedRef for toml::Table {
+	type KeyRef<'a> = &'a String where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl SimpleKeyedRef for toml::Table {
+	crate::simple_keyed_ref!();
+}
+
+impl Len for toml::Table {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.is_empty()
+	}
+}
+
+impl WithCapacity for toml::Table {
+	#[inline(always)]
+	fn with_capacity(capacity: usize) -> Self {
+		Self::with_capacity(capacity)
+	}
+}
+
+impl Iter for toml::Table {
+	type Iter<'a> = toml::map::Values<'a>;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.values()
+	}
+}
+
+impl MapIter for toml::Table {
+	type Iter<'a> = toml::map::Iter<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl MapIterMut for toml::Table {
+	type IterMut<'a> = toml::map::IterMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.iter_mut()
+	}
+}
+
+impl<'a, Q: ?Sized> Get<&'a Q> for toml::Table
+where
+	String: Borrow<Q>,
+	Q: Ord + Eq + Hash,
+{
+	#[inline(always)]
+	fn get(&self, q: &'a Q) -> Option<&toml::Value> {
+		self.get(q)
+	}
+}
+
+impl<'a, Q: ?Sized> GetMut<&'a Q> for toml::Table
+where
+	String: Borrow<Q>,
+	Q: Ord + Eq + Hash,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, q: &'a Q) -> Option<&mut toml::Value> {
+		self.get_mut(q)
+	}
+}
+
+impl<'a, Q: ?Sized> GetKeyValue<&'a Q> for toml::Table
+where
+	String: Borrow<Q>,
+	Q: Ord + Eq + Hash,
+{
+	#[inline(always)]
+	fn get_key_value(&self, q: &'a Q) -> Option<(&String, &toml::Value)> {
+		self.get_key_value(q)
+	}
+}
+
+impl<'a, Q: ?Sized> GetKeyValueMut<&'a Q> for toml::Table
+where
+	String: Borrow<Q>,
+	Q: Ord + Eq + Hash,
+{
+	/// Returns the key-value pair of the given key, with a mutable value.
+	///
+	/// `toml` does not provide this operation: a missing key is detected
+	/// with a keyed lookup, but a present key is found by searching the map
+	/// linearly, in `O(n)` time.
+	fn get_key_value_mut(&mut self, q: &'a Q) -> Option<(&String, &mut toml::Value)> {
+		if !self.contains_key(q) {
+			return None;
+		}
+
+		self.iter_mut().find(|(key, _)| (*key).borrow() == q)
+	}
+}
+
+impl MapInsert<String> for toml::Table {
+	type Output = Option<toml::Value>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: String, value: toml::Value) -> Option<toml::Value> {
+		self.insert(key, value)
+	}
+}
+
+impl MapInsertAll<String> for toml::Table {
+	#[inline(always)]
+	fn insert_all<I: IntoIterator<Item = (String, toml::Value)>>(&mut self, pairs: I) {
+		self.extend(pairs)
+	}
+}
+
+impl<'a, Q: ?Sized> Remove<&'a Q> for toml::Table
+where
+	String: Borrow<Q>,
+	Q: Ord + Eq + Hash,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<toml::Value> {
+		self.remove(key)
+	}
+}
+
+impl Clear for toml::Table {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.clear()
+	}
+}
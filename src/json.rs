@@ -0,0 +1,1094 @@
+//! JSON Pointer ([RFC 6901](https://www.rfc-editor.org/rfc/rfc6901)) navigation.
+//!
+//! The [`JsonValue`] trait abstracts over the JSON value types of the supported
+//! JSON crates, exposing their objects and arrays through the collection traits
+//! of this crate.
+//! It provides JSON Pointer based accessors, written once for every backend,
+//! as well as [JSON Merge Patch](https://www.rfc-editor.org/rfc/rfc7396) and
+//! [JSON Patch](https://www.rfc-editor.org/rfc/rfc6902) application.
+//!
+//! ## Example
+//!
+//! ```
+//! use cc_traits::json::JsonValue;
+//! use serde_json::json;
+//!
+//! let mut value = json!({ "a": [1, { "b/c": 2 }] });
+//! assert_eq!(value.get_pointer("/a/1/b~1c"), Some(&json!(2)));
+//!
+//! value.insert_pointer("/a/0", json!(0)).unwrap();
+//! value.insert_pointer("/a/-", json!(3)).unwrap();
+//! assert_eq!(value.remove_pointer("/a/2"), Some(json!({ "b/c": 2 })));
+//! assert_eq!(value, json!({ "a": [0, 1, 3] }));
+//!
+//! value.merge_patch(&json!({ "a": null, "b": { "c": true } }));
+//! assert_eq!(value, json!({ "b": { "c": true } }));
+//!
+//! value
+//!     .apply_patch(&json!([
+//!         { "op": "test", "path": "/b/c", "value": true },
+//!         { "op": "move", "from": "/b/c", "path": "/d" }
+//!     ]))
+//!     .unwrap();
+//! assert_eq!(value, json!({ "b": {}, "d": true }));
+//!
+//! let error = value
+//!     .apply_patch(&json!([{ "op": "remove", "path": "/e" }]))
+//!     .unwrap_err();
+//! assert_eq!(error.path(), "/e");
+//! ```
+use crate::{
+	Clear, Collection, Get, GetMut, Iter, Keyed, Len, MapInsert, MapIter, PushBack, Remove,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef,
+};
+use alloc::{
+	borrow::Cow,
+	format,
+	string::{String, ToString},
+	vec::Vec,
+};
+use core::{fmt, ops::Deref};
+
+#[cfg(feature = "ijson")]
+pub use crate::impls::ijson::ObjectKey;
+
+/// JSON value, whose objects and arrays are collections.
+pub trait JsonValue: Sized {
+	/// Type of object keys.
+	type Key: for<'a> From<&'a str> + Deref<Target = str>;
+
+	/// Type of JSON objects.
+	type Object: Default
+		+ Into<Self>
+		+ Keyed<Key = Self::Key, Item = Self>
+		+ SimpleCollectionRef
+		+ SimpleKeyedRef
+		+ MapIter
+		+ Clear
+		+ SimpleCollectionMut
+		+ for<'a> Get<&'a str>
+		+ for<'a> GetMut<&'a str>
+		+ for<'a> Remove<&'a str>
+		+ MapInsert<Self::Key, Output = Option<Self>>;
+
+	/// Type of JSON arrays.
+	type Array: Collection<Item = Self>
+		+ SimpleCollectionRef
+		+ SimpleCollectionMut
+		+ Len
+		+ Get<usize>
+		+ GetMut<usize>
+		+ PushBack
+		+ Remove<usize>
+		+ Iter
+		+ Clear;
+
+	/// Returns the `null` value.
+	fn null() -> Self;
+
+	/// Returns the kind of this value.
+	fn kind(&self) -> ValueKind;
+
+	/// Checks if this value is `null`.
+	fn is_null(&self) -> bool;
+
+	/// Returns the string represented by this value, if any.
+	fn as_str(&self) -> Option<&str>;
+
+	/// Returns the object represented by this value, if any.
+	fn as_object(&self) -> Option<&Self::Object>;
+
+	/// Returns the object represented by this value, if any, mutably.
+	fn as_object_mut(&mut self) -> Option<&mut Self::Object>;
+
+	/// Returns the array represented by this value, if any.
+	fn as_array(&self) -> Option<&Self::Array>;
+
+	/// Returns the array represented by this value, if any, mutably.
+	fn as_array_mut(&mut self) -> Option<&mut Self::Array>;
+
+	/// Inserts an item at the given index of an array, shifting the following
+	/// items.
+	///
+	/// Panics if `index` is greater than the length of the array.
+	fn array_insert(array: &mut Self::Array, index: usize, item: Self);
+
+	/// Returns an iterator over the items of this value if it is an array,
+	/// or the member values of this value if it is an object.
+	///
+	/// Other values have no items.
+	#[inline(always)]
+	fn values(&self) -> Values<'_, Self> {
+		Values::new(self)
+	}
+
+	/// Returns the value referenced by the given JSON Pointer.
+	///
+	/// Returns `None` if the pointer is invalid or does not reference any value.
+	fn get_pointer(&self, pointer: &str) -> Option<&Self> {
+		parse(pointer)
+			.ok()?
+			.iter()
+			.try_fold(self, |value, token| child(value, token))
+	}
+
+	/// Returns the value referenced by the given JSON Pointer, mutably.
+	///
+	/// Returns `None` if the pointer is invalid or does not reference any value.
+	fn get_pointer_mut(&mut self, pointer: &str) -> Option<&mut Self> {
+		parse(pointer)
+			.ok()?
+			.iter()
+			.try_fold(self, |value, token| child_mut(value, token))
+	}
+
+	/// Inserts a value at the location referenced by the given JSON Pointer.
+	///
+	/// The parent of the location must exist.
+	/// If the parent is an object, the value is added or replaces the existing
+	/// member, which is returned.
+	/// If the parent is an array, the value is inserted at the given index,
+	/// shifting the following items, or appended if the last reference token
+	/// is `-`.
+	/// The empty pointer replaces the whole value.
+	fn insert_pointer(&mut self, pointer: &str, value: Self) -> Result<Option<Self>, PointerError> {
+		insert(self, pointer, value).map_err(|(e, _)| e)
+	}
+
+	/// Removes the value referenced by the given JSON Pointer.
+	///
+	/// Returns `None` if the pointer is invalid, empty, or does not reference
+	/// any value.
+	fn remove_pointer(&mut self, pointer: &str) -> Option<Self> {
+		let mut tokens = parse(pointer).ok()?;
+		let last = tokens.pop()?;
+		let parent = tokens
+			.iter()
+			.try_fold(self, |value, token| child_mut(value, token))?;
+
+		if let Some(object) = parent.as_object_mut() {
+			return object.remove(&last);
+		}
+
+		parent.as_array_mut()?.remove(parse_index(&last)?)
+	}
+
+	/// Applies the given JSON Merge Patch (RFC 7396) to this value.
+	///
+	/// If the patch is an object, each of its members is merged into this
+	/// value (turned into an empty object first if it is not an object),
+	/// `null` members removing the corresponding member.
+	/// Otherwise the patch replaces this value.
+	fn merge_patch(&mut self, patch: &Self)
+	where
+		Self: Clone,
+	{
+		let patch = match patch.as_object() {
+			Some(patch) => patch,
+			None => {
+				*self = patch.clone();
+				return;
+			}
+		};
+
+		if self.as_object().is_none() {
+			*self = Self::Object::default().into();
+		}
+
+		let object = self.as_object_mut().unwrap();
+		for (key, value) in patch.iter() {
+			let key: &str = <Self::Object as SimpleKeyedRef>::into_ref(key);
+			let value = <Self::Object as SimpleCollectionRef>::into_ref(value);
+			if value.is_null() {
+				object.remove(key);
+			} else if object.contains(key) {
+				Self::Object::into_mut(object.get_mut(key).unwrap()).merge_patch(value)
+			} else {
+				let mut member = Self::null();
+				member.merge_patch(value);
+				object.insert(Self::Key::from(key), member);
+			}
+		}
+	}
+
+	/// Applies the given JSON Patch (RFC 6902) to this value.
+	///
+	/// The patch must be an array of operations.
+	/// The operations are applied in order, and if any of them fails,
+	/// the previous ones are undone, leaving this value unchanged.
+	fn apply_patch(&mut self, patch: &Self) -> Result<(), PatchError>
+	where
+		Self: Clone + PartialEq,
+	{
+		let operations = patch
+			.as_array()
+			.ok_or_else(|| PatchError::new(0, "", PatchErrorKind::InvalidPatch))?;
+
+		let mut undo = Vec::new();
+		for i in 0..operations.len() {
+			let operation = Self::Array::into_ref(operations.get(i).unwrap());
+			if let Err(e) = apply_operation(self, i, operation, &mut undo) {
+				for step in undo.into_iter().rev() {
+					step.apply(self)
+				}
+
+				return Err(e);
+			}
+		}
+
+		Ok(())
+	}
+}
+
+/// Kind of JSON value.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ValueKind {
+	/// `null` value.
+	Null,
+
+	/// Boolean value.
+	Bool,
+
+	/// Number.
+	Number,
+
+	/// String.
+	String,
+
+	/// Array.
+	Array,
+
+	/// Object.
+	Object,
+}
+
+impl ValueKind {
+	/// Checks if values of this kind are collections (arrays or objects).
+	#[inline(always)]
+	pub fn is_collection(&self) -> bool {
+		matches!(self, Self::Array | Self::Object)
+	}
+}
+
+/// Iterator over the items of a JSON array or the member values of a
+/// JSON object.
+///
+/// Created by [`JsonValue::values`].
+pub struct Values<'a, V: JsonValue + 'a> {
+	inner: ValuesInner<'a, V>,
+}
+
+enum ValuesInner<'a, V: JsonValue + 'a> {
+	Object(<V::Object as MapIter>::Iter<'a>),
+	Array(<V::Array as Iter>::Iter<'a>),
+	Empty,
+}
+
+impl<'a, V: JsonValue> Values<'a, V> {
+	/// Creates an iterator over the items or member values of the given value.
+	pub fn new(value: &'a V) -> Self {
+		let inner = if let Some(object) = value.as_object() {
+			ValuesInner::Object(object.iter())
+		} else if let Some(array) = value.as_array() {
+			ValuesInner::Array(array.iter())
+		} else {
+			ValuesInner::Empty
+		};
+
+		Self { inner }
+	}
+}
+
+impl<'a, V: JsonValue> Iterator for Values<'a, V> {
+	type Item = &'a V;
+
+	fn next(&mut self) -> Option<&'a V> {
+		match &mut self.inner {
+			ValuesInner::Object(inner) => inner
+				.next()
+				.map(|(_, value)| <V::Object as SimpleCollectionRef>::into_ref(value)),
+			ValuesInner::Array(inner) => inner.next().map(V::Array::into_ref),
+			ValuesInner::Empty => None,
+		}
+	}
+}
+
+/// JSON Pointer error.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PointerError {
+	/// The pointer is not empty and does not start with `/`,
+	/// or contains an invalid `~` escape sequence.
+	Invalid,
+
+	/// The parent of the referenced location does not exist.
+	NotFound,
+
+	/// The parent of the referenced location is neither an object nor an array.
+	NotContainer,
+
+	/// The reference token is not a valid index in the parent array.
+	InvalidIndex,
+}
+
+impl fmt::Display for PointerError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::Invalid => write!(f, "invalid JSON pointer"),
+			Self::NotFound => write!(f, "parent value not found"),
+			Self::NotContainer => write!(f, "parent value is not an object or array"),
+			Self::InvalidIndex => write!(f, "invalid array index"),
+		}
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PointerError {}
+
+/// JSON Patch error.
+///
+/// Reports the index of the failing operation and the path it applies to.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct PatchError {
+	index: usize,
+	path: String,
+	kind: PatchErrorKind,
+}
+
+impl PatchError {
+	fn new(index: usize, path: &str, kind: PatchErrorKind) -> Self {
+		Self {
+			index,
+			path: path.to_string(),
+			kind,
+		}
+	}
+
+	/// Returns the index of the failing operation in the patch.
+	#[inline(always)]
+	pub fn index(&self) -> usize {
+		self.index
+	}
+
+	/// Returns the JSON Pointer of the failing location.
+	#[inline(always)]
+	pub fn path(&self) -> &str {
+		&self.path
+	}
+
+	/// Returns the kind of error.
+	#[inline(always)]
+	pub fn kind(&self) -> PatchErrorKind {
+		self.kind
+	}
+}
+
+impl fmt::Display for PatchError {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		write!(
+			f,
+			"{} at `{}` (operation {})",
+			self.kind, self.path, self.index
+		)
+	}
+}
+
+#[cfg(feature = "std")]
+impl std::error::Error for PatchError {}
+
+/// JSON Patch error kind.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum PatchErrorKind {
+	/// The patch is not an array.
+	InvalidPatch,
+
+	/// The operation is malformed.
+	InvalidOperation,
+
+	/// The operation target location could not be written.
+	Pointer(PointerError),
+
+	/// The referenced value does not exist.
+	NotFound,
+
+	/// A value is moved into one of its children.
+	MoveIntoChild,
+
+	/// The `test` operation failed.
+	TestFailed,
+}
+
+impl fmt::Display for PatchErrorKind {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		match self {
+			Self::InvalidPatch => write!(f, "patch is not an array"),
+			Self::InvalidOperation => write!(f, "invalid operation"),
+			Self::Pointer(e) => e.fmt(f),
+			Self::NotFound => write!(f, "value not found"),
+			Self::MoveIntoChild => write!(f, "cannot move a value into one of its children"),
+			Self::TestFailed => write!(f, "test failed"),
+		}
+	}
+}
+
+/// Step undoing a part of a JSON Patch operation.
+enum Undo<V> {
+	/// Removes the value at the given location.
+	Remove(String),
+
+	/// Inserts the value at the given location.
+	Insert(String, V),
+
+	/// Replaces the value at the given location.
+	Replace(String, V),
+
+	/// Moves the value at the first location to the second location.
+	Move(String, String),
+}
+
+impl<V: JsonValue> Undo<V> {
+	/// Undoes the step.
+	///
+	/// Steps are undone in reverse order, so their location always exists.
+	fn apply(self, value: &mut V) {
+		match self {
+			Self::Remove(path) => {
+				value.remove_pointer(&path);
+			}
+			Self::Insert(path, old_value) => {
+				let _ = value.insert_pointer(&path, old_value);
+			}
+			Self::Replace(path, old_value) => {
+				if let Some(target) = value.get_pointer_mut(&path) {
+					*target = old_value
+				}
+			}
+			Self::Move(path, from) => {
+				if let Some(moved) = value.remove_pointer(&path) {
+					let _ = value.insert_pointer(&from, moved);
+				}
+			}
+		}
+	}
+}
+
+/// Applies a single JSON Patch operation, recording the steps undoing it.
+fn apply_operation<V>(
+	value: &mut V,
+	index: usize,
+	operation: &V,
+	undo: &mut Vec<Undo<V>>,
+) -> Result<(), PatchError>
+where
+	V: JsonValue + Clone + PartialEq,
+{
+	let member = |name: &str| {
+		operation
+			.as_object()
+			.and_then(|o| o.get(name))
+			.map(<V::Object as SimpleCollectionRef>::into_ref)
+	};
+	let string = |name: &str| {
+		member(name)
+			.and_then(V::as_str)
+			.ok_or_else(|| PatchError::new(index, "", PatchErrorKind::InvalidOperation))
+	};
+	let operand = |path: &str| {
+		member("value")
+			.ok_or_else(|| PatchError::new(index, path, PatchErrorKind::InvalidOperation))
+	};
+
+	let op = string("op")?;
+	let path = string("path")?;
+	let error = |path: &str, kind| PatchError::new(index, path, kind);
+
+	let add = |value: &mut V, new_value: V, undo: &mut Vec<Undo<V>>| {
+		let old_value = value
+			.insert_pointer(path, new_value)
+			.map_err(|e| error(path, PatchErrorKind::Pointer(e)))?;
+		undo.push(match old_value {
+			Some(old_value) => Undo::Insert(path.to_string(), old_value),
+			None => Undo::Remove(added_location(value, path)),
+		});
+		Ok(())
+	};
+
+	match op {
+		"add" => add(value, operand(path)?.clone(), undo),
+		"remove" => {
+			let old_value = value
+				.remove_pointer(path)
+				.ok_or_else(|| error(path, PatchErrorKind::NotFound))?;
+			undo.push(Undo::Insert(path.to_string(), old_value));
+			Ok(())
+		}
+		"replace" => {
+			let new_value = operand(path)?.clone();
+			let target = value
+				.get_pointer_mut(path)
+				.ok_or_else(|| error(path, PatchErrorKind::NotFound))?;
+			let old_value = core::mem::replace(target, new_value);
+			undo.push(Undo::Replace(path.to_string(), old_value));
+			Ok(())
+		}
+		"move" => {
+			let from = string("from")?;
+			if from == path {
+				return if value.get_pointer(from).is_some() {
+					Ok(())
+				} else {
+					Err(error(from, PatchErrorKind::NotFound))
+				};
+			}
+
+			if path
+				.strip_prefix(from)
+				.is_some_and(|rest| rest.starts_with('/'))
+			{
+				return Err(error(path, PatchErrorKind::MoveIntoChild));
+			}
+
+			let moved = value
+				.remove_pointer(from)
+				.ok_or_else(|| error(from, PatchErrorKind::NotFound))?;
+			match insert(value, path, moved) {
+				Ok(old_value) => {
+					if let Some(old_value) = old_value {
+						undo.push(Undo::Insert(path.to_string(), old_value))
+					}
+
+					undo.push(Undo::Move(added_location(value, path), from.to_string()));
+					Ok(())
+				}
+				Err((e, moved)) => {
+					let _ = value.insert_pointer(from, moved);
+					Err(error(path, PatchErrorKind::Pointer(e)))
+				}
+			}
+		}
+		"copy" => {
+			let from = string("from")?;
+			let copied = value
+				.get_pointer(from)
+				.cloned()
+				.ok_or_else(|| error(from, PatchErrorKind::NotFound))?;
+			add(value, copied, undo)
+		}
+		"test" => {
+			let expected = operand(path)?;
+			match value.get_pointer(path) {
+				Some(actual) if actual == expected => Ok(()),
+				Some(_) => Err(error(path, PatchErrorKind::TestFailed)),
+				None => Err(error(path, PatchErrorKind::NotFound)),
+			}
+		}
+		_ => Err(error(path, PatchErrorKind::InvalidOperation)),
+	}
+}
+
+/// Inserts a value at the location referenced by the given JSON Pointer.
+///
+/// See [`JsonValue::insert_pointer`].
+/// The value is given back on error.
+fn insert<V: JsonValue>(
+	value: &mut V,
+	pointer: &str,
+	new_value: V,
+) -> Result<Option<V>, (PointerError, V)> {
+	let mut tokens = match parse(pointer) {
+		Ok(tokens) => tokens,
+		Err(e) => return Err((e, new_value)),
+	};
+	let last = match tokens.pop() {
+		Some(last) => last,
+		None => return Ok(Some(core::mem::replace(value, new_value))),
+	};
+
+	let parent = match tokens
+		.iter()
+		.try_fold(value, |value, token| child_mut(value, token))
+	{
+		Some(parent) => parent,
+		None => return Err((PointerError::NotFound, new_value)),
+	};
+
+	if let Some(object) = parent.as_object_mut() {
+		return Ok(object.insert(V::Key::from(&last), new_value));
+	}
+
+	let array = match parent.as_array_mut() {
+		Some(array) => array,
+		None => return Err((PointerError::NotContainer, new_value)),
+	};
+
+	if last == "-" {
+		array.push_back(new_value);
+		return Ok(None);
+	}
+
+	match parse_index(&last) {
+		Some(index) if index <= array.len() => {
+			V::array_insert(array, index, new_value);
+			Ok(None)
+		}
+		_ => Err((PointerError::InvalidIndex, new_value)),
+	}
+}
+
+/// Returns the location of a value added at the given path, replacing the
+/// trailing `-` reference token of an array by the index of the value.
+fn added_location<V: JsonValue>(value: &V, path: &str) -> String {
+	if let Some(parent) = path.strip_suffix("/-") {
+		if let Some(array) = value.get_pointer(parent).and_then(V::as_array) {
+			return format!("{}/{}", parent, array.len() - 1);
+		}
+	}
+
+	path.to_string()
+}
+
+/// Parses the given JSON Pointer into its unescaped reference tokens.
+pub(crate) fn parse(pointer: &str) -> Result<Vec<Cow<'_, str>>, PointerError> {
+	if pointer.is_empty() {
+		return Ok(Vec::new());
+	}
+
+	pointer
+		.strip_prefix('/')
+		.ok_or(PointerError::Invalid)?
+		.split('/')
+		.map(unescape)
+		.collect()
+}
+
+/// Unescapes a reference token.
+fn unescape(token: &str) -> Result<Cow<'_, str>, PointerError> {
+	if !token.contains('~') {
+		return Ok(Cow::Borrowed(token));
+	}
+
+	let mut result = String::with_capacity(token.len());
+	let mut chars = token.chars();
+	while let Some(c) = chars.next() {
+		match c {
+			'~' => match chars.next() {
+				Some('0') => result.push('~'),
+				Some('1') => result.push('/'),
+				_ => return Err(PointerError::Invalid),
+			},
+			c => result.push(c),
+		}
+	}
+
+	Ok(Cow::Owned(result))
+}
+
+/// Parses an array index reference token.
+///
+/// Leading zeros are not allowed.
+fn parse_index(token: &str) -> Option<usize> {
+	if token.is_empty()
+		|| (token.len() > 1 && token.starts_with('0'))
+		|| !token.bytes().all(|b| b.is_ascii_digit())
+	{
+		return None;
+	}
+
+	token.parse().ok()
+}
+
+/// Returns the child of the given value referenced by the given token.
+fn child<'a, V: JsonValue>(value: &'a V, token: &str) -> Option<&'a V> {
+	if let Some(object) = value.as_object() {
+		return object
+			.get(token)
+			.map(<V::Object as SimpleCollectionRef>::into_ref);
+	}
+
+	let array = value.as_array()?;
+	array.get(parse_index(token)?).map(V::Array::into_ref)
+}
+
+/// Returns the child of the given value referenced by the given token, mutably.
+fn child_mut<'a, V: JsonValue>(value: &'a mut V, token: &str) -> Option<&'a mut V> {
+	if value.as_object().is_some() {
+		let object = value.as_object_mut()?;
+		return object.get_mut(token).map(V::Object::into_mut);
+	}
+
+	let array = value.as_array_mut()?;
+	array.get_mut(parse_index(token)?).map(V::Array::into_mut)
+}
+
+#[cfg(all(test, feature = "serde_json"))]
+mod tests {
+	use super::{JsonValue, PatchErrorKind, PointerError, ValueKind};
+	use crate::{Clear, Collection, Get, Iter, Len, SimpleCollectionRef};
+	use core::fmt::Debug;
+	use serde_json::json;
+
+	fn escapes<V: JsonValue + PartialEq + Debug>(from: impl Fn(serde_json::Value) -> V) {
+		let value = from(json!({ "a/b": 1, "m~n": 2, "~1": 3 }));
+		assert_eq!(value.get_pointer(""), Some(&value));
+		assert_eq!(value.get_pointer("/a~1b"), Some(&from(json!(1))));
+		assert_eq!(value.get_pointer("/m~0n"), Some(&from(json!(2))));
+		assert_eq!(value.get_pointer("/~01"), Some(&from(json!(3))));
+		assert_eq!(value.get_pointer("/a/b"), None);
+		assert_eq!(value.get_pointer("/m~2n"), None);
+		assert_eq!(value.get_pointer("/m~"), None);
+		assert_eq!(value.get_pointer("a~1b"), None);
+
+		let mut value = value;
+		assert_eq!(
+			value.insert_pointer("/m~", from(json!(4))),
+			Err(PointerError::Invalid)
+		);
+		assert_eq!(
+			value.insert_pointer("m~0n", from(json!(4))),
+			Err(PointerError::Invalid)
+		);
+		assert_eq!(
+			value.insert_pointer("/m~0n", from(json!(4))),
+			Ok(Some(from(json!(2))))
+		);
+		assert_eq!(value.remove_pointer("/~01"), Some(from(json!(3))));
+		assert_eq!(value, from(json!({ "a/b": 1, "m~n": 4 })));
+	}
+
+	fn indexes<V: JsonValue + PartialEq + Debug>(from: impl Fn(serde_json::Value) -> V) {
+		let mut value = from(json!({ "a": [1, 2, 3], "n": 0 }));
+		assert_eq!(value.get_pointer("/a/0"), Some(&from(json!(1))));
+		for invalid in [
+			"/a/01",
+			"/a/x",
+			"/a/+1",
+			"/a/-",
+			"/a/3",
+			"/a/",
+			"/a/99999999999999999999",
+		] {
+			assert_eq!(value.get_pointer(invalid), None, "{}", invalid);
+			assert_eq!(value.remove_pointer(invalid), None, "{}", invalid);
+		}
+
+		for invalid in ["/a/01", "/a/x", "/a/4", "/a/99999999999999999999"] {
+			assert_eq!(
+				value.insert_pointer(invalid, V::null()),
+				Err(PointerError::InvalidIndex),
+				"{}",
+				invalid
+			);
+		}
+
+		assert_eq!(
+			value.insert_pointer("/b/0", V::null()),
+			Err(PointerError::NotFound)
+		);
+		assert_eq!(
+			value.insert_pointer("/n/0", V::null()),
+			Err(PointerError::NotContainer)
+		);
+
+		assert_eq!(value.insert_pointer("/a/0", from(json!(0))), Ok(None));
+		assert_eq!(value.insert_pointer("/a/4", from(json!(4))), Ok(None));
+		assert_eq!(value.insert_pointer("/a/-", from(json!(5))), Ok(None));
+		assert_eq!(value.remove_pointer("/a/1"), Some(from(json!(1))));
+		assert_eq!(value, from(json!({ "a": [0, 2, 3, 4, 5], "n": 0 })));
+
+		assert_eq!(
+			value.insert_pointer("", from(json!([]))),
+			Ok(Some(from(json!({ "a": [0, 2, 3, 4, 5], "n": 0 }))))
+		);
+		assert_eq!(value.remove_pointer(""), None);
+		assert_eq!(value, from(json!([])));
+	}
+
+	fn values<V>(from: impl Fn(serde_json::Value) -> V)
+	where
+		V: JsonValue
+			+ Collection<Item = V>
+			+ SimpleCollectionRef
+			+ Len
+			+ for<'a> Get<&'a str>
+			+ Get<usize>
+			+ Iter
+			+ Clear
+			+ PartialEq
+			+ Debug,
+	{
+		fn member<'a, V: Collection<Item = V> + SimpleCollectionRef + Get<&'a str>>(
+			value: &'a V,
+			key: &'a str,
+		) -> Option<&'a V> {
+			value.get(key).map(V::into_ref)
+		}
+
+		fn item<V: Collection<Item = V> + SimpleCollectionRef + Get<usize>>(
+			value: &V,
+			index: usize,
+		) -> Option<&V> {
+			value.get(index).map(V::into_ref)
+		}
+
+		let mut value = from(json!({ "a": [1, "b"], "c": null, "d": true }));
+		assert_eq!(value.kind(), ValueKind::Object);
+		assert!(value.kind().is_collection());
+		assert_eq!(value.len(), 3);
+		assert_eq!(value.values().count(), 3);
+		assert_eq!(Iter::iter(&value).count(), 3);
+		assert_eq!(item(&value, 0), None);
+
+		let array = member(&value, "a").unwrap();
+		assert_eq!(array.kind(), ValueKind::Array);
+		assert_eq!(array.len(), 2);
+		assert_eq!(member(array, "b"), None);
+		assert_eq!(
+			array.values().map(JsonValue::kind).collect::<Vec<_>>(),
+			[ValueKind::Number, ValueKind::String]
+		);
+		assert_eq!(item(array, 1).and_then(JsonValue::as_str), Some("b"));
+
+		let null = member(&value, "c").unwrap();
+		assert_eq!(null.kind(), ValueKind::Null);
+		assert!(!null.kind().is_collection());
+		assert!(null.is_empty());
+		assert_eq!(null.values().count(), 0);
+		assert_eq!(member(&value, "d").map(V::kind), Some(ValueKind::Bool));
+
+		let mut number = from(json!(1));
+		number.clear();
+		assert_eq!(number, from(json!(1)));
+		value.clear();
+		assert_eq!(value, from(json!({})));
+	}
+
+	fn merge_patches<V: JsonValue + Clone + PartialEq + Debug>(
+		from: impl Fn(serde_json::Value) -> V,
+	) {
+		let mut value = from(json!({ "a": "b", "c": { "d": "e", "f": "g" }, "h": [1] }));
+		value.merge_patch(&from(
+			json!({ "a": "z", "c": { "f": null }, "h": { "i": 1 } }),
+		));
+		assert_eq!(
+			value,
+			from(json!({ "a": "z", "c": { "d": "e" }, "h": { "i": 1 } }))
+		);
+
+		value.merge_patch(&from(json!({ "j": { "k": null, "l": 2 } })));
+		assert_eq!(value.get_pointer("/j"), Some(&from(json!({ "l": 2 }))));
+
+		value.merge_patch(&from(json!(["c"])));
+		assert_eq!(value, from(json!(["c"])));
+	}
+
+	fn patches<V: JsonValue + Clone + PartialEq + Debug>(from: impl Fn(serde_json::Value) -> V) {
+		let mut value = from(json!({ "a": [1, 2], "b": { "c": 3 } }));
+		value
+			.apply_patch(&from(json!([
+				{ "op": "add", "path": "/a/1", "value": 4 },
+				{ "op": "add", "path": "/b/d", "value": 5 },
+				{ "op": "remove", "path": "/a/0" },
+				{ "op": "replace", "path": "/b/c", "value": 6 },
+				{ "op": "move", "from": "/b/d", "path": "/a/-" },
+				{ "op": "move", "from": "/a", "path": "/a" },
+				{ "op": "copy", "from": "/b", "path": "/e" },
+				{ "op": "test", "path": "/a", "value": [4, 2, 5] }
+			])))
+			.unwrap();
+		assert_eq!(
+			value,
+			from(json!({ "a": [4, 2, 5], "b": { "c": 6 }, "e": { "c": 6 } }))
+		);
+
+		let error = value
+			.apply_patch(&from(json!([
+				{ "op": "move", "from": "/b", "path": "/b/f" }
+			])))
+			.unwrap_err();
+		assert_eq!(error.kind(), PatchErrorKind::MoveIntoChild);
+		assert_eq!(error.path(), "/b/f");
+		assert_eq!(error.index(), 0);
+
+		// `/bc` is not a child of `/b`.
+		value
+			.apply_patch(&from(
+				json!([{ "op": "move", "from": "/b", "path": "/bc" }]),
+			))
+			.unwrap();
+		assert_eq!(
+			value,
+			from(json!({ "a": [4, 2, 5], "bc": { "c": 6 }, "e": { "c": 6 } }))
+		);
+	}
+
+	fn patch_errors<V: JsonValue + Clone + PartialEq + Debug>(
+		from: impl Fn(serde_json::Value) -> V,
+	) {
+		let original = from(json!({ "a": [1, 2], "b": { "c": 3 } }));
+		let mut value = original.clone();
+		let check = |value: &mut V, patch, index, path: &str, kind| {
+			let error = value.apply_patch(&from(patch)).unwrap_err();
+			assert_eq!(
+				(error.index(), error.path(), error.kind()),
+				(index, path, kind)
+			);
+		};
+
+		// Every successful operation is undone when a later one fails.
+		check(
+			&mut value,
+			json!([
+				{ "op": "add", "path": "/a/0", "value": 0 },
+				{ "op": "add", "path": "/a/-", "value": 3 },
+				{ "op": "add", "path": "/b/c", "value": 4 },
+				{ "op": "add", "path": "/d", "value": 5 },
+				{ "op": "remove", "path": "/a/1" },
+				{ "op": "replace", "path": "/a/0", "value": 6 },
+				{ "op": "move", "from": "/b/c", "path": "/a/1" },
+				{ "op": "move", "from": "/d", "path": "/b/c" },
+				{ "op": "copy", "from": "/a", "path": "/" },
+				{ "op": "add", "path": "", "value": [] },
+				{ "op": "test", "path": "", "value": {} }
+			]),
+			10,
+			"",
+			PatchErrorKind::TestFailed,
+		);
+		assert_eq!(value, original);
+
+		check(
+			&mut value,
+			json!([
+				{ "op": "remove", "path": "/a/0" },
+				{ "op": "move", "from": "/b", "path": "/a/5" }
+			]),
+			1,
+			"/a/5",
+			PatchErrorKind::Pointer(PointerError::InvalidIndex),
+		);
+		assert_eq!(value, original);
+
+		check(
+			&mut value,
+			json!([{ "op": "remove", "path": "/a/2" }]),
+			0,
+			"/a/2",
+			PatchErrorKind::NotFound,
+		);
+		check(
+			&mut value,
+			json!([{ "op": "replace", "path": "/e", "value": 0 }]),
+			0,
+			"/e",
+			PatchErrorKind::NotFound,
+		);
+		check(
+			&mut value,
+			json!([{ "op": "copy", "from": "/e", "path": "/f" }]),
+			0,
+			"/e",
+			PatchErrorKind::NotFound,
+		);
+		check(
+			&mut value,
+			json!([{ "op": "test", "path": "/b/c", "value": 4 }]),
+			0,
+			"/b/c",
+			PatchErrorKind::TestFailed,
+		);
+		check(
+			&mut value,
+			json!([{ "op": "add", "path": "/e/f", "value": 0 }]),
+			0,
+			"/e/f",
+			PatchErrorKind::Pointer(PointerError::NotFound),
+		);
+		check(
+			&mut value,
+			json!([{ "op": "add", "path": "/a" }]),
+			0,
+			"/a",
+			PatchErrorKind::InvalidOperation,
+		);
+		check(
+			&mut value,
+			json!([{ "op": "swap", "path": "/a" }]),
+			0,
+			"/a",
+			PatchErrorKind::InvalidOperation,
+		);
+		check(
+			&mut value,
+			json!({ "op": "remove", "path": "/a" }),
+			0,
+			"",
+			PatchErrorKind::InvalidPatch,
+		);
+		assert_eq!(value, original);
+	}
+
+	#[test]
+	fn serde_json_escapes() {
+		escapes(|value| value)
+	}
+
+	#[test]
+	fn serde_json_indexes() {
+		indexes(|value| value)
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_escapes() {
+		escapes(|value| ijson::to_value(value).unwrap())
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_indexes() {
+		indexes(|value| ijson::to_value(value).unwrap())
+	}
+
+	#[test]
+	fn serde_json_values() {
+		values(|value| value)
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_values() {
+		values(|value| ijson::to_value(value).unwrap())
+	}
+
+	#[test]
+	fn serde_json_merge_patches() {
+		merge_patches(|value| value)
+	}
+
+	#[test]
+	fn serde_json_patches() {
+		patches(|value| value)
+	}
+
+	#[test]
+	fn serde_json_patch_errors() {
+		patch_errors(|value| value)
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_merge_patches() {
+		merge_patches(|value| ijson::to_value(value).unwrap())
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_patches() {
+		patches(|value| ijson::to_value(value).unwrap())
+	}
+
+	#[cfg(feature = "ijson")]
+	#[test]
+	fn ijson_patch_errors() {
+		patch_errors(|value| ijson::to_value(value).unwrap())
+	}
+}
@@ -94,6 +94,45 @@
 //! As of version 0.8.0, those traits are also available without the `nightly`
 //! feature as regular trait definitions.
 //!
+//! # Building collections
+//!
+//! The [`Build`] iterator extension builds any collection implementing
+//! [`WithCapacity`] and [`PushBack`], [`Insert`] or [`MapInsert`]
+//! from an iterator, and [`convert`] moves the items of a collection into
+//! another representation (e.g. `convert::<VecDeque<_>>(vec)`).
+//!
+//! # Reordering sequences
+//!
+//! The [`Swap`], [`Reverse`], [`Rotate`] and [`Sort`] traits reorder the
+//! items of a sequence in place. They are implemented for every sequence
+//! exposing contiguous storage through [`ContiguousMut`].
+//! Other sequences implementing [`Swap`] and [`Len`] get [`Reverse`] and
+//! [`Rotate`] with an empty `impl` block, and the [`reorder::indexed`]
+//! module implements [`Swap`] and [`Sort`] for any
+//! [`GetMut<usize>`](GetMut) + [`Len`] sequence whose items implement
+//! [`Default`].
+//!
+//! # Collection wrappers
+//!
+//! This crate also provides some generic collection types built on top of
+//! those traits, that can wrap any collection implementing them:
+//!
+//!   - [`ReadOnly`] and [`ReadOnlyRef`], read-only views over a collection.
+//!   - [`Sorted`], keeping a sequence sorted.
+//!   - [`VecMap`], a map backed by a sorted sequence of key-value pairs.
+//!   - [`Bag`], a multiset counting the occurrences of each item in a map.
+//!   - [`bi_map::BiMap`], a [`BiMap`] keeping two maps in sync.
+//!   - [`multi_map::MultiMap`], a [`MultiMap`] storing the values of each key in a collection.
+//!   - [`ScopedMap`], a stack of maps where inner scopes shadow outer scopes.
+//!   - [`Ring`], a fixed-capacity ring buffer overwriting its oldest item.
+//!   - `Sharded`, distributing the keys of a map across multiple shards (requires the `std` feature).
+//!   - [`Bounded`], enforcing a maximum length with a configurable eviction policy.
+//!   - `Lru`, a least-recently-used cache generic over its backing map (requires the `alloc` feature).
+//!   - `Tiered`, a two-tier map promoting the bindings of a slow map into a fast map (requires the `alloc` feature).
+//!   - `Expiring`, a map whose entries expire after a time-to-live (requires the `alloc` feature).
+//!   - [`Observed`], notifying an [`observed::Listener`] of every modification.
+//!   - `Transactional`, recording an undo log to support commit and rollback (requires the `alloc` feature).
+//!
 //! # Standard library
 //!
 //! By default, all the traits defined in this crate are implemented (when relevant)
@@ -109,8 +148,19 @@
 //!
 //!   - [`slab`](https://crates.io/crates/slab) providing the `Slab` collection.
 //!   - [`smallvec`](https://crates.io/crates/smallvec) providing the `SmallVec` collection.
-//!   - [`serde_json`](https://crates.io/crates/serde_json) providing the `Map<String, Value>` collection for JSON objects.
-//!   - [`ijson`](https://crates.io/crates/ijson) providing the `IObject` and `IArray` collections.
+//!   - [`serde_json`](https://crates.io/crates/serde_json) providing the `Map<String, Value>` collection for JSON objects, and the `Value` collection.
+//!   - [`ijson`](https://crates.io/crates/ijson) providing the `IObject`, `IArray` and `IValue` collections.
+//!   - [`toml`](https://crates.io/crates/toml) providing the `Table` collection for TOML tables
+//!     (arrays are `Vec<Value>`).
+//!   - [`serde_yaml`](https://crates.io/crates/serde_yaml) providing the `Mapping` collection for YAML mappings
+//!     (sequences are `Vec<Value>`).
+//!
+//! The `serde` feature provides the `serde` module, with `#[serde(with = "...")]`
+//! helpers to serialize and deserialize any collection implementing the traits.
+//!
+//! When one of the JSON crates is enabled, the `json` module provides
+//! [JSON Pointer](https://www.rfc-editor.org/rfc/rfc6901) navigation and JSON patches
+//! for their JSON values.
 #![cfg_attr(not(feature = "std"), no_std)]
 #![cfg_attr(feature = "nightly", feature(trait_alias))]
 
@@ -118,8 +168,58 @@
 extern crate alloc;
 extern crate core;
 
+pub mod bag;
+pub mod bi_map;
+mod build;
+pub mod bounded;
+#[cfg(feature = "alloc")]
+pub mod expiring;
 mod impls;
+#[cfg(any(feature = "serde_json", feature = "ijson"))]
+pub mod json;
 mod macros;
+pub mod multi_map;
+pub mod observed;
+#[cfg(feature = "alloc")]
+pub mod lru;
+mod read_only;
+pub mod reorder;
+mod ring;
+mod scoped_map;
+#[cfg(feature = "serde")]
+pub mod serde;
+#[cfg(feature = "std")]
+pub mod sharded;
+pub mod sorted;
+#[cfg(feature = "alloc")]
+pub mod tiered;
+#[cfg(feature = "alloc")]
+mod transactional;
+pub mod vec_map;
+
+pub use bag::Bag;
+pub use bi_map::Overwritten;
+pub use build::*;
+pub use bounded::Bounded;
+#[cfg(feature = "alloc")]
+pub use expiring::Expiring;
+#[cfg(feature = "alloc")]
+pub use lru::Lru;
+pub use observed::Observed;
+pub use read_only::*;
+#[cfg(feature = "alloc")]
+pub use reorder::Sort;
+pub use reorder::{ContiguousMut, Reverse, Rotate, Swap};
+pub use ring::*;
+pub use scoped_map::*;
+#[cfg(feature = "std")]
+pub use sharded::Sharded;
+pub use sorted::Sorted;
+#[cfg(feature = "alloc")]
+pub use tiered::Tiered;
+#[cfg(feature = "alloc")]
+pub use transactional::*;
+pub use vec_map::VecMap;
 
 #[cfg(feature = "nightly")]
 mod alias;
@@ -131,7 +231,7 @@ mod non_alias;
 #[cfg(not(feature = "nightly"))]
 pub use non_alias::*;
 
-use core::ops::{Deref, DerefMut};
+use core::ops::{Deref, DerefMut, RangeBounds};
 
 /// Abstract collection.
 pub trait Collection {
@@ -404,6 +504,132 @@ pub trait Remove<T>: Collection {
 	fn remove(&mut self, key: T) -> Option<Self::Item>;
 }
 
+/// Mutable collection where multiple elements can be pushed on the back at once.
+///
+/// The provided methods push the elements one by one.
+/// Implementations should override them with a faster native operation
+/// when there is one, and implement [`PushBackAll::reserve_all`] when they
+/// implement [`Reserve`].
+pub trait PushBackAll: PushBack {
+	/// Reserve capacity for at least `additional` more elements before
+	/// a bulk insertion.
+	///
+	/// Called by the provided methods with the lower bound of the size hint
+	/// of the elements. Does nothing by default.
+	#[inline(always)]
+	fn reserve_all(&mut self, _additional: usize) {}
+
+	/// Push the given elements on the back of the collection, in order.
+	fn push_back_all<I: IntoIterator<Item = Self::Item>>(&mut self, elements: I) {
+		let elements = elements.into_iter();
+		self.reserve_all(elements.size_hint().0);
+		for element in elements {
+			self.push_back(element);
+		}
+	}
+
+	/// Push clones of the given elements on the back of the collection, in order.
+	fn push_back_slice(&mut self, elements: &[Self::Item])
+	where
+		Self::Item: Clone,
+	{
+		self.push_back_all(elements.iter().cloned())
+	}
+}
+
+/// Mutable collection where multiple elements can be inserted at once.
+///
+/// The provided method inserts the elements one by one, discarding the
+/// output of each insertion: collections whose insertion output is needed
+/// (such as the keys of a slab) should not implement this trait.
+/// Implementations should override it with a faster native operation
+/// when there is one, and implement [`InsertAll::reserve_all`] when they
+/// implement [`Reserve`].
+pub trait InsertAll: Insert {
+	/// Reserve capacity for at least `additional` more elements before
+	/// a bulk insertion.
+	///
+	/// Called by [`InsertAll::insert_all`] with the lower bound of the size
+	/// hint of the elements. Does nothing by default.
+	#[inline(always)]
+	fn reserve_all(&mut self, _additional: usize) {}
+
+	/// Insert the given elements in the collection, in order.
+	fn insert_all<I: IntoIterator<Item = Self::Item>>(&mut self, elements: I) {
+		let elements = elements.into_iter();
+		self.reserve_all(elements.size_hint().0);
+		for element in elements {
+			self.insert(element);
+		}
+	}
+}
+
+/// Mutable map where multiple key-value pairs can be inserted at once.
+///
+/// The provided method inserts the pairs one by one.
+/// Implementations should override it with a faster native operation
+/// when there is one, and implement [`MapInsertAll::reserve_all`] when they
+/// implement [`Reserve`].
+pub trait MapInsertAll<K>: MapInsert<K> {
+	/// Reserve capacity for at least `additional` more key-value pairs before
+	/// a bulk insertion.
+	///
+	/// Called by [`MapInsertAll::insert_all`] with the lower bound of the
+	/// size hint of the pairs. Does nothing by default.
+	#[inline(always)]
+	fn reserve_all(&mut self, _additional: usize) {}
+
+	/// Insert the given key-value pairs in the collection, in order.
+	fn insert_all<I: IntoIterator<Item = (K, Self::Item)>>(&mut self, pairs: I) {
+		let pairs = pairs.into_iter();
+		self.reserve_all(pairs.size_hint().0);
+		for (key, value) in pairs {
+			self.insert(key, value);
+		}
+	}
+}
+
+/// Mutable collection where multiple elements can be removed at once.
+///
+/// The provided method removes the elements one by one.
+/// None of the collections of this crate implements this trait, since none
+/// of them offers a native bulk removal.
+pub trait RemoveAll<T>: Remove<T> {
+	/// Remove the elements identified by the given keys, in order,
+	/// and return the number of removed elements.
+	fn remove_all<I: IntoIterator<Item = T>>(&mut self, keys: I) -> usize {
+		let mut count = 0;
+		for key in keys {
+			if self.remove(key).is_some() {
+				count += 1
+			}
+		}
+
+		count
+	}
+}
+
+/// Collection whose elements can be moved out of another collection of the same type.
+pub trait Append {
+	/// Move all the elements of `other` into `self`, leaving `other` empty.
+	///
+	/// For sequences, the elements of `other` are moved to the back of `self`.
+	fn append(&mut self, other: &mut Self);
+}
+
+/// Collection that can be split in two at a given key.
+///
+/// The key is an index for sequences, and a key for ordered maps and sets.
+pub trait SplitOff<K>: Sized {
+	/// Split the collection in two at the given key.
+	///
+	/// Returns a new collection containing the elements at and after the key,
+	/// leaving the elements before the key in `self`.
+	///
+	/// Sequences panic if the index is greater than their length.
+	fn split_off(&mut self, key: K) -> Self;
+}
+
 /// Mutable collection where elements can be popped from the front.
 pub trait PopFront: Collection {
 	/// Remove the front element of the collection and return it (if any).
@@ -459,3 +685,100 @@ pub trait MapIterMut: KeyedRef + CollectionMut {
 
 	fn iter_mut(&mut self) -> Self::IterMut<'_>;
 }
+
+/// Ordered collection whose items can be iterated over a range.
+pub trait OrderedRange<Q: ?Sized>: CollectionRef {
+	/// Range iterator type.
+	type Range<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the items of the collection included in the given range,
+	/// in ascending order.
+	fn range<R: RangeBounds<Q>>(&self, range: R) -> Self::Range<'_>;
+}
+
+/// Multi-map, associating each key to multiple values.
+pub trait MultiMap<K, V>: Keyed<Key = K, Item = V> + CollectionRef {
+	/// Iterator over the values associated to a key.
+	type Values<'a>: Iterator<Item = Self::ItemRef<'a>>
+	where
+		Self: 'a;
+
+	/// Create an iterator over the values associated to the given key.
+	fn get_all(&self, key: &K) -> Self::Values<'_>;
+
+	/// Checks if at least one value is associated to the given key.
+	fn contains_key(&self, key: &K) -> bool;
+
+	/// Returns the number of keys associated to at least one value.
+	fn len_keys(&self) -> usize;
+
+	/// Returns the total number of values in the multi-map.
+	fn len_values(&self) -> usize;
+}
+
+/// Mutable multi-map.
+pub trait MultiMapMut<K, V>: MultiMap<K, V> {
+	/// Associates the given value to the given key.
+	///
+	/// Returns `false` if the value was not added, for instance
+	/// because the values of each key are stored in a set that already
+	/// contains the value.
+	fn insert(&mut self, key: K, value: V) -> bool;
+
+	/// Removes one occurrence of the given key-value pair.
+	fn remove_one(&mut self, key: &K, value: &V) -> Option<V>;
+
+	/// Removes all the values associated to the given key.
+	///
+	/// Returns the number of removed values.
+	fn remove_all(&mut self, key: &K) -> usize;
+}
+
+/// Bidirectional map, associating each left value to at most one right value
+/// and each right value to at most one left value.
+pub trait BiMap<L, R> {
+	/// Type of references to left values.
+	type LeftRef<'a>: Deref<Target = L>
+	where
+		Self: 'a;
+
+	/// Type of references to right values.
+	type RightRef<'a>: Deref<Target = R>
+	where
+		Self: 'a;
+
+	/// Returns a reference to the right value associated to the given left value (if any).
+	fn get_by_left(&self, left: &L) -> Option<Self::RightRef<'_>>;
+
+	/// Returns a reference to the left value associated to the given right value (if any).
+	fn get_by_right(&self, right: &R) -> Option<Self::LeftRef<'_>>;
+
+	/// Checks if the given left value is associated to a right value.
+	#[inline(always)]
+	fn contains_left(&self, left: &L) -> bool {
+		self.get_by_left(left).is_some()
+	}
+
+	/// Checks if the given right value is associated to a left value.
+	#[inline(always)]
+	fn contains_right(&self, right: &R) -> bool {
+		self.get_by_right(right).is_some()
+	}
+}
+
+/// Mutable bidirectional map.
+pub trait BiMapMut<L, R>: BiMap<L, R> {
+	/// Associates the given left and right values,
+	/// removing any existing pair sharing one of them.
+	///
+	/// Returns the removed pairs.
+	fn insert(&mut self, left: L, right: R) -> Overwritten<L, R>;
+
+	/// Removes the pair with the given left value.
+	fn remove_by_left(&mut self, left: &L) -> Option<(L, R)>;
+
+	/// Removes the pair with the given right value.
+	fn remove_by_right(&mut self, right: &R) -> Option<(L, R)>;
+}
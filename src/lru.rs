@@ -0,0 +1,527 @@
+//! Least-recently-used cache.
+use crate::{
+	tiered::Evict, Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue,
+	GetMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapMut, Remove, SimpleCollectionMut,
+	SimpleCollectionRef, SimpleKeyedRef,
+};
+use alloc::vec::Vec;
+use core::{cell::Cell, fmt};
+
+/// Index used to represent the absence of node.
+const NIL: usize = usize::MAX;
+
+struct Node<K, V> {
+	key: K,
+	value: V,
+	prev: Cell<usize>,
+	next: Cell<usize>,
+}
+
+/// Least-recently-used cache.
+///
+/// The cache stores at most `capacity` entries, and `capacity` is never `0`
+/// so that an inserted entry is always stored.
+/// When a new key is inserted in a full cache, the least recently used
+/// entry is evicted.
+/// Entries are accessed through the standard map traits ([`Get`],
+/// [`GetMut`], [`GetKeyValue`], [`MapInsert`], [`Remove`], etc.) so that
+/// `Lru<M, K, V>` is itself a [`MapMut<K, V>`](MapMut).
+/// Any access to an entry marks it as the most recently used one.
+///
+/// The cache is generic over its backing map `M`, mapping each key to the
+/// index of its entry, such as `HashMap<K, usize>` or `BTreeMap<K, usize>`.
+///
+/// Since recency is updated by immutable accesses, the cache is not [`Sync`].
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, Lru, MapInsert};
+/// use std::collections::HashMap;
+///
+/// let mut cache: Lru<HashMap<&str, usize>, &str, i32> = Lru::new(2);
+/// cache.insert("a", 1);
+/// cache.insert("b", 2);
+/// cache.get(&"a"); // `a` is now more recently used than `b`.
+/// cache.insert("c", 3); // evicts `b`.
+///
+/// assert_eq!(cache.get(&"a"), Some(&1));
+/// assert_eq!(cache.get(&"b"), None);
+/// assert_eq!(cache.get(&"c"), Some(&3));
+/// ```
+pub struct Lru<M, K, V> {
+	map: M,
+	nodes: Vec<Node<K, V>>,
+	head: Cell<usize>,
+	tail: Cell<usize>,
+	capacity: usize,
+}
+
+impl<M: Default, K, V> Lru<M, K, V> {
+	/// Creates a new empty cache with the given capacity.
+	///
+	/// Panics if `capacity` is `0`.
+	pub fn new(capacity: usize) -> Self {
+		assert!(capacity > 0, "`capacity` must be positive");
+		Self {
+			map: M::default(),
+			nodes: Vec::new(),
+			head: Cell::new(NIL),
+			tail: Cell::new(NIL),
+			capacity,
+		}
+	}
+}
+
+impl<M, K, V> Lru<M, K, V> {
+	/// Returns a reference to the value associated to the given key,
+	/// without marking it as used.
+	pub fn peek<'a, Q: ?Sized>(&self, key: &'a Q) -> Option<&V>
+	where
+		M: Get<&'a Q> + Collection<Item = usize>,
+	{
+		self.map.get(key).map(|i| &self.nodes[*i].value)
+	}
+
+	/// Returns the least recently used entry, without marking it as used.
+	pub fn peek_lru(&self) -> Option<(&K, &V)> {
+		match self.tail.get() {
+			NIL => None,
+			i => Some((&self.nodes[i].key, &self.nodes[i].value)),
+		}
+	}
+
+	/// Removes and returns the least recently used entry.
+	pub fn pop_lru(&mut self) -> Option<(K, V)>
+	where
+		M: MapMut<K, usize>,
+	{
+		match self.tail.get() {
+			NIL => None,
+			i => {
+				self.map.remove(&self.nodes[i].key);
+				Some(self.remove_node(i))
+			}
+		}
+	}
+
+	/// Changes the capacity of the cache,
+	/// evicting the least recently used entries if necessary.
+	///
+	/// Panics if `capacity` is `0`.
+	pub fn set_capacity(&mut self, capacity: usize)
+	where
+		M: MapMut<K, usize>,
+	{
+		assert!(capacity > 0, "`capacity` must be positive");
+		self.capacity = capacity;
+		while self.nodes.len() > capacity {
+			self.pop_lru();
+		}
+	}
+
+	/// Detaches the given node from the recency list.
+	fn unlink(&self, i: usize) {
+		let prev = self.nodes[i].prev.get();
+		let next = self.nodes[i].next.get();
+
+		match prev {
+			NIL => self.head.set(next),
+			p => self.nodes[p].next.set(next),
+		}
+
+		match next {
+			NIL => self.tail.set(prev),
+			n => self.nodes[n].prev.set(prev),
+		}
+	}
+
+	/// Attaches the given detached node at the front of the recency list.
+	fn link_front(&self, i: usize) {
+		let head = self.head.get();
+		self.nodes[i].prev.set(NIL);
+		self.nodes[i].next.set(head);
+
+		match head {
+			NIL => self.tail.set(i),
+			h => self.nodes[h].prev.set(i),
+		}
+
+		self.head.set(i)
+	}
+
+	/// Marks the given node as the most recently used.
+	fn touch(&self, i: usize) {
+		if self.head.get() != i {
+			self.unlink(i);
+			self.link_front(i)
+		}
+	}
+
+	/// Removes the given node.
+	///
+	/// The node key must already have been removed from the map.
+	/// The map entry of the node moved to index `i` is updated.
+	fn remove_node(&mut self, i: usize) -> (K, V)
+	where
+		M: MapMut<K, usize>,
+	{
+		self.unlink(i);
+		let node = self.nodes.swap_remove(i);
+
+		if i < self.nodes.len() {
+			let prev = self.nodes[i].prev.get();
+			let next = self.nodes[i].next.get();
+
+			match prev {
+				NIL => self.head.set(i),
+				p => self.nodes[p].next.set(i),
+			}
+
+			match next {
+				NIL => self.tail.set(i),
+				n => self.nodes[n].prev.set(i),
+			}
+
+			if let Some(mut index) = self.map.get_mut(&self.nodes[i].key) {
+				*index = i
+			}
+		}
+
+		(node.key, node.value)
+	}
+
+	/// Returns an iterator over the entries of the cache,
+	/// from the most recently used to the least recently used.
+	///
+	/// Iterating does not change the recency of the entries.
+	pub fn iter(&self) -> Iter<'_, K, V> {
+		Iter {
+			nodes: &self.nodes,
+			current: self.head.get(),
+			len: self.nodes.len(),
+		}
+	}
+}
+
+impl<M, K: fmt::Debug, V: fmt::Debug> fmt::Debug for Lru<M, K, V> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_map().entries(self.iter()).finish()
+	}
+}
+
+impl<M, K, V> Collection for Lru<M, K, V> {
+	type Item = V;
+}
+
+impl<M, K, V> CollectionRef for Lru<M, K, V> {
+	type ItemRef<'a> = &'a V where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<M, K, V> CollectionMut for Lru<M, K, V> {
+	type ItemMut<'a> = &'a mut V where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<M, K, V> SimpleCollectionRef for Lru<M, K, V> {
+	crate::simple_collection_ref!();
+}
+
+impl<M, K, V> SimpleCollectionMut for Lru<M, K, V> {
+	crate::simple_collection_mut!();
+}
+
+impl<M, K, V> Keyed for Lru<M, K, V> {
+	type Key = K;
+}
+
+impl<M, K, V> KeyedRef for Lru<M, K, V> {
+	type KeyRef<'a> = &'a K where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<M, K, V> SimpleKeyedRef for Lru<M, K, V> {
+	crate::simple_keyed_ref!();
+}
+
+impl<M, K, V> Len for Lru<M, K, V> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.nodes.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.nodes.is_empty()
+	}
+}
+
+impl<M, K, V> Capacity for Lru<M, K, V> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity
+	}
+}
+
+impl<'a, Q: ?Sized, M, K, V> Get<&'a Q> for Lru<M, K, V>
+where
+	M: Get<&'a Q> + Collection<Item = usize>,
+{
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		let i = *self.map.get(key)?;
+		self.touch(i);
+		Some(&self.nodes[i].value)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: &'a Q) -> bool {
+		self.map.contains(key)
+	}
+}
+
+impl<'a, Q: ?Sized, M, K, V> GetMut<&'a Q> for Lru<M, K, V>
+where
+	M: Get<&'a Q> + Collection<Item = usize>,
+{
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		let i = *self.map.get(key)?;
+		self.touch(i);
+		Some(&mut self.nodes[i].value)
+	}
+}
+
+impl<'a, Q: ?Sized, M, K, V> GetKeyValue<&'a Q> for Lru<M, K, V>
+where
+	M: Get<&'a Q> + Collection<Item = usize>,
+{
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		let i = *self.map.get(key)?;
+		self.touch(i);
+		let node = &self.nodes[i];
+		Some((&node.key, &node.value))
+	}
+}
+
+impl<M, K: Clone, V> MapInsert<K> for Lru<M, K, V>
+where
+	M: MapMut<K, usize>,
+{
+	type Output = Option<V>;
+
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		if let Some(i) = self.map.get(&key).map(|i| *i) {
+			self.touch(i);
+			return Some(core::mem::replace(&mut self.nodes[i].value, value));
+		}
+
+		if self.nodes.len() >= self.capacity {
+			self.pop_lru();
+		}
+
+		let i = self.nodes.len();
+		self.nodes.push(Node {
+			key: key.clone(),
+			value,
+			prev: Cell::new(NIL),
+			next: Cell::new(NIL),
+		});
+		self.link_front(i);
+		self.map.insert(key, i);
+		None
+	}
+}
+
+impl<M: MapMut<K, usize>, K, V> Evict for Lru<M, K, V> {
+	/// Removes the least recently used entry.
+	#[inline(always)]
+	fn evict(&mut self) -> Option<(K, V)> {
+		self.pop_lru()
+	}
+}
+
+impl<'a, Q: ?Sized, M, K, V> Remove<&'a Q> for Lru<M, K, V>
+where
+	M: MapMut<K, usize> + Remove<&'a Q>,
+{
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		let i = self.map.remove(key)?;
+		Some(self.remove_node(i).1)
+	}
+}
+
+impl<M: Clear, K, V> Clear for Lru<M, K, V> {
+	fn clear(&mut self) {
+		self.map.clear();
+		self.nodes.clear();
+		self.head.set(NIL);
+		self.tail.set(NIL)
+	}
+}
+
+impl<M, K, V> crate::Iter for Lru<M, K, V> {
+	type Iter<'a> = Values<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		Values { inner: self.iter() }
+	}
+}
+
+impl<M, K, V> MapIter for Lru<M, K, V> {
+	type Iter<'a> = Iter<'a, K, V> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+/// Iterator over the entries of a [`Lru`] cache,
+/// from the most recently used to the least recently used.
+pub struct Iter<'a, K, V> {
+	nodes: &'a [Node<K, V>],
+	current: usize,
+	len: usize,
+}
+
+impl<'a, K, V> Clone for Iter<'a, K, V> {
+	fn clone(&self) -> Self {
+		Self {
+			nodes: self.nodes,
+			current: self.current,
+			len: self.len,
+		}
+	}
+}
+
+impl<'a, K, V> Iterator for Iter<'a, K, V> {
+	type Item = (&'a K, &'a V);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		(self.len, Some(self.len))
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		match self.current {
+			NIL => None,
+			i => {
+				let node = &self.nodes[i];
+				self.current = node.next.get();
+				self.len -= 1;
+				Some((&node.key, &node.value))
+			}
+		}
+	}
+}
+
+impl<'a, K, V> ExactSizeIterator for Iter<'a, K, V> {}
+
+/// Iterator over the values of a [`Lru`] cache,
+/// from the most recently used to the least recently used.
+pub struct Values<'a, K, V> {
+	inner: Iter<'a, K, V>,
+}
+
+impl<'a, K, V> Clone for Values<'a, K, V> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+impl<'a, K, V> Iterator for Values<'a, K, V> {
+	type Item = &'a V;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|(_, v)| v)
+	}
+}
+
+impl<'a, K, V> ExactSizeIterator for Values<'a, K, V> {}
+
+#[cfg(test)]
+mod tests {
+	use super::Lru;
+	use crate::{tiered::Evict, Clear, Get, GetMut, Len, MapInsert, Remove};
+	use std::collections::BTreeMap;
+
+	type Cache = Lru<BTreeMap<u32, usize>, u32, u32>;
+
+	fn keys(cache: &Cache) -> Vec<u32> {
+		cache.iter().map(|(k, _)| *k).collect()
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_capacity() {
+		Cache::new(0);
+	}
+
+	#[test]
+	#[should_panic]
+	fn zero_capacity_update() {
+		Cache::new(1).set_capacity(0);
+	}
+
+	#[test]
+	fn capacity_one() {
+		let mut cache = Cache::new(1);
+		assert_eq!(cache.insert(1, 10), None);
+		assert_eq!(cache.insert(2, 20), None);
+		assert_eq!(cache.len(), 1);
+		assert_eq!(cache.get(&1), None);
+		assert_eq!(cache.insert(2, 21), Some(20));
+	}
+
+	#[test]
+	fn accesses_update_recency() {
+		let mut cache = Cache::new(3);
+		cache.insert(1, 10);
+		cache.insert(2, 20);
+		cache.insert(3, 30);
+		assert_eq!(keys(&cache), [3, 2, 1]);
+
+		cache.get(&1);
+		*cache.get_mut(&2).unwrap() += 1;
+		assert_eq!(keys(&cache), [2, 1, 3]);
+
+		assert_eq!(cache.peek(&3), Some(&30));
+		assert_eq!(cache.peek_lru(), Some((&3, &30)));
+		assert_eq!(cache.evict(), Some((3, 30)));
+		assert_eq!(keys(&cache), [2, 1]);
+	}
+
+	#[test]
+	fn removal_keeps_indexes_consistent() {
+		let mut cache = Cache::new(4);
+		for k in 0..4 {
+			cache.insert(k, k * 10);
+		}
+
+		// Removing the first node moves the last one in its slot.
+		assert_eq!(cache.remove(&0), Some(0));
+		assert_eq!(cache.remove(&0), None);
+		assert_eq!(cache.get(&3), Some(&30));
+		assert_eq!(keys(&cache), [3, 2, 1]);
+
+		cache.set_capacity(2);
+		assert_eq!(keys(&cache), [3, 2]);
+		cache.insert(4, 40);
+		assert_eq!(keys(&cache), [4, 3]);
+
+		cache.clear();
+		assert!(cache.is_empty());
+		assert_eq!(cache.pop_lru(), None);
+		cache.insert(5, 50);
+		assert_eq!(keys(&cache), [5]);
+	}
+}
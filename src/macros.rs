@@ -213,3 +213,116 @@ macro_rules! simple_keyed_ref {
 		}
 	};
 }
+
+/// Implements the immutable collection traits of a wrapper type by
+/// forwarding them to the wrapped collection `C`.
+///
+/// Additional type parameters of the wrapper may be marked `?Sized`.
+///
+/// The forwarded traits are [`Collection`](crate::Collection),
+/// [`CollectionRef`](crate::CollectionRef), [`Keyed`](crate::Keyed),
+/// [`KeyedRef`](crate::KeyedRef), [`Len`](crate::Len), [`Get`](crate::Get),
+/// [`GetKeyValue`](crate::GetKeyValue), [`Iter`](crate::Iter),
+/// [`MapIter`](crate::MapIter) and [`Index`](core::ops::Index), which is
+/// required by the [`Vec`](crate::Vec) alias.
+macro_rules! forward_read {
+	($ty:ident < C $(, $param:ident $(: ?$sized:ident)?)* > . $field:ident) => {
+		impl<C: $crate::Collection $(, $param $(: ?$sized)?)*> $crate::Collection for $ty<C $(, $param)*> {
+			type Item = C::Item;
+		}
+
+		impl<C: $crate::CollectionRef $(, $param $(: ?$sized)?)*> $crate::CollectionRef for $ty<C $(, $param)*> {
+			type ItemRef<'a> = C::ItemRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_item_ref<'short, 'long: 'short>(
+				r: Self::ItemRef<'long>,
+			) -> Self::ItemRef<'short>
+			where
+				Self: 'long,
+			{
+				C::upcast_item_ref(r)
+			}
+		}
+
+		impl<C: $crate::Keyed $(, $param $(: ?$sized)?)*> $crate::Keyed for $ty<C $(, $param)*> {
+			type Key = C::Key;
+		}
+
+		impl<C: $crate::KeyedRef $(, $param $(: ?$sized)?)*> $crate::KeyedRef for $ty<C $(, $param)*> {
+			type KeyRef<'a> = C::KeyRef<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn upcast_key_ref<'short, 'long: 'short>(
+				r: Self::KeyRef<'long>,
+			) -> Self::KeyRef<'short>
+			where
+				Self: 'long,
+			{
+				C::upcast_key_ref(r)
+			}
+		}
+
+		impl<C: $crate::Len $(, $param $(: ?$sized)?)*> $crate::Len for $ty<C $(, $param)*> {
+			#[inline(always)]
+			fn len(&self) -> usize {
+				self.$field.len()
+			}
+
+			#[inline(always)]
+			fn is_empty(&self) -> bool {
+				self.$field.is_empty()
+			}
+		}
+
+		impl<Q, C: $crate::Get<Q> $(, $param $(: ?$sized)?)*> $crate::Get<Q> for $ty<C $(, $param)*> {
+			#[inline(always)]
+			fn get(&self, key: Q) -> Option<Self::ItemRef<'_>> {
+				self.$field.get(key)
+			}
+
+			#[inline(always)]
+			fn contains(&self, key: Q) -> bool {
+				self.$field.contains(key)
+			}
+		}
+
+		impl<Q, C: $crate::GetKeyValue<Q> $(, $param $(: ?$sized)?)*> $crate::GetKeyValue<Q>
+			for $ty<C $(, $param)*>
+		{
+			#[inline(always)]
+			fn get_key_value(&self, key: Q) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+				self.$field.get_key_value(key)
+			}
+		}
+
+		impl<C: $crate::Iter $(, $param $(: ?$sized)?)*> $crate::Iter for $ty<C $(, $param)*> {
+			type Iter<'a> = C::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				$crate::Iter::iter(&self.$field)
+			}
+		}
+
+		impl<C: $crate::MapIter $(, $param $(: ?$sized)?)*> $crate::MapIter for $ty<C $(, $param)*> {
+			type Iter<'a> = C::Iter<'a> where Self: 'a;
+
+			#[inline(always)]
+			fn iter(&self) -> Self::Iter<'_> {
+				$crate::MapIter::iter(&self.$field)
+			}
+		}
+
+		impl<I, C: core::ops::Index<I> $(, $param $(: ?$sized)?)*> core::ops::Index<I> for $ty<C $(, $param)*> {
+			type Output = C::Output;
+
+			#[inline(always)]
+			fn index(&self, index: I) -> &C::Output {
+				&self.$field[index]
+			}
+		}
+	};
+}
+
+pub(crate) use forward_read;
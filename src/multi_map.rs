@@ -0,0 +1,333 @@
+//! Multi-map.
+use crate::{
+	Clear, Collection, CollectionRef, Get, GetMut, Insert, Iter, Keyed, Len, MapInsert, PushBack,
+	Remove, SimpleCollectionRef,
+};
+use core::marker::PhantomData;
+
+/// Strategy used by a [`MultiMap`] to add and remove values
+/// in the collection `C` associated to each key.
+pub trait Bucket<C: Collection> {
+	/// Adds a value to the given bucket.
+	///
+	/// Returns `false` if the value has not been added.
+	fn insert(bucket: &mut C, value: C::Item) -> bool;
+
+	/// Removes one occurrence of the given value from the given bucket.
+	fn remove(bucket: &mut C, value: &C::Item) -> Option<C::Item>;
+}
+
+/// Bucket strategy for sequences, using [`PushBack`] to add values.
+///
+/// The same value can be associated multiple times to a key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct List;
+
+impl<C> Bucket<C> for List
+where
+	C: PushBack + Iter + SimpleCollectionRef + Remove<usize>,
+	C::Item: PartialEq,
+{
+	#[inline(always)]
+	fn insert(bucket: &mut C, value: C::Item) -> bool {
+		bucket.push_back(value);
+		true
+	}
+
+	#[inline(always)]
+	fn remove(bucket: &mut C, value: &C::Item) -> Option<C::Item> {
+		let index = bucket.iter().position(|v| C::into_ref(v) == value)?;
+		bucket.remove(index)
+	}
+}
+
+/// Bucket strategy for sets, using [`Insert`] to add values.
+///
+/// A value is associated at most once to a key.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Unique;
+
+impl<C> Bucket<C> for Unique
+where
+	C: Insert<Output = bool> + for<'a> Remove<&'a <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn insert(bucket: &mut C, value: C::Item) -> bool {
+		bucket.insert(value)
+	}
+
+	#[inline(always)]
+	fn remove(bucket: &mut C, value: &C::Item) -> Option<C::Item> {
+		bucket.remove(value)
+	}
+}
+
+/// Multi-map backed by a map `M` from keys to collections of values.
+///
+/// Values are added to the collection associated to their key according
+/// to the bucket strategy `B`: [`List`] (the default) for sequences such as
+/// `HashMap<K, Vec<V>>`, or [`Unique`] for sets such as
+/// `BTreeMap<K, BTreeSet<V>>`.
+/// A key is removed from the inner map as soon as it has no more values,
+/// so the inner map never contains empty collections.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{multi_map, MultiMap as _, MultiMapMut};
+/// use std::collections::{BTreeMap, BTreeSet, HashMap};
+///
+/// let mut list: multi_map::MultiMap<HashMap<&str, Vec<i32>>> = multi_map::MultiMap::new();
+/// list.insert("a", 1);
+/// list.insert("a", 1);
+/// list.insert("b", 2);
+///
+/// assert_eq!(list.get_all(&"a").copied().collect::<Vec<_>>(), [1, 1]);
+/// assert_eq!(list.len_keys(), 2);
+/// assert_eq!(list.len_values(), 3);
+///
+/// assert_eq!(list.remove_one(&"a", &1), Some(1));
+/// assert_eq!(list.remove_all(&"a"), 1);
+/// assert!(!list.contains_key(&"a"));
+///
+/// let mut set: multi_map::MultiMap<BTreeMap<&str, BTreeSet<i32>>, multi_map::Unique> =
+///   multi_map::MultiMap::new();
+/// assert!(set.insert("a", 1));
+/// assert!(!set.insert("a", 1));
+/// assert_eq!(set.len_values(), 1);
+/// ```
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct MultiMap<M, B = List> {
+	map: M,
+	len: usize,
+	bucket: PhantomData<B>,
+}
+
+impl<M, B> MultiMap<M, B> {
+	/// Creates a new empty multi-map.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		M: Default,
+	{
+		Self {
+			map: M::default(),
+			len: 0,
+			bucket: PhantomData,
+		}
+	}
+
+	/// Returns a reference to the inner map.
+	#[inline(always)]
+	pub fn inner(&self) -> &M {
+		&self.map
+	}
+
+	/// Consumes the multi-map and returns the inner map.
+	#[inline(always)]
+	pub fn into_inner(self) -> M {
+		self.map
+	}
+}
+
+impl<M: Collection, B> Collection for MultiMap<M, B>
+where
+	M::Item: Collection,
+{
+	type Item = <M::Item as Collection>::Item;
+}
+
+impl<M: Collection, B> CollectionRef for MultiMap<M, B>
+where
+	M::Item: CollectionRef,
+{
+	type ItemRef<'a> = <M::Item as CollectionRef>::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		<M::Item as CollectionRef>::upcast_item_ref(r)
+	}
+}
+
+impl<M: Keyed, B> Keyed for MultiMap<M, B>
+where
+	M::Item: Collection,
+{
+	type Key = M::Key;
+}
+
+impl<M, B> Len for MultiMap<M, B> {
+	/// Returns the total number of values in the multi-map.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.len
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.len == 0
+	}
+}
+
+impl<M: Clear, B> Clear for MultiMap<M, B> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.map.clear();
+		self.len = 0
+	}
+}
+
+impl<K, V, M, B> crate::MultiMap<K, V> for MultiMap<M, B>
+where
+	M: Keyed<Key = K> + Len + SimpleCollectionRef + for<'a> Get<&'a K>,
+	M::Item: Collection<Item = V> + Iter,
+{
+	type Values<'a> = Values<'a, M::Item> where Self: 'a;
+
+	#[inline(always)]
+	fn get_all(&self, key: &K) -> Values<'_, M::Item> {
+		Values {
+			inner: self.map.get(key).map(|bucket| M::into_ref(bucket).iter()),
+		}
+	}
+
+	#[inline(always)]
+	fn contains_key(&self, key: &K) -> bool {
+		self.map.contains(key)
+	}
+
+	#[inline(always)]
+	fn len_keys(&self) -> usize {
+		self.map.len()
+	}
+
+	#[inline(always)]
+	fn len_values(&self) -> usize {
+		self.len
+	}
+}
+
+impl<K, V, M, B> crate::MultiMapMut<K, V> for MultiMap<M, B>
+where
+	M: Keyed<Key = K>
+		+ Len
+		+ SimpleCollectionRef
+		+ MapInsert<K>
+		+ for<'a> Get<&'a K>
+		+ for<'a> GetMut<&'a K>
+		+ for<'a> Remove<&'a K>,
+	M::Item: Collection<Item = V> + Iter + Len + Default,
+	B: Bucket<M::Item>,
+{
+	fn insert(&mut self, key: K, value: V) -> bool {
+		if let Some(mut bucket) = self.map.get_mut(&key) {
+			let added = B::insert(&mut bucket, value);
+			if added {
+				self.len += 1
+			}
+
+			return added;
+		}
+
+		let mut bucket = M::Item::default();
+		let added = B::insert(&mut bucket, value);
+		if added {
+			self.map.insert(key, bucket);
+			self.len += 1
+		}
+
+		added
+	}
+
+	fn remove_one(&mut self, key: &K, value: &V) -> Option<V> {
+		let (removed, empty) = {
+			let mut bucket = self.map.get_mut(key)?;
+			let removed = B::remove(&mut *bucket, value)?;
+			(removed, bucket.is_empty())
+		};
+
+		if empty {
+			self.map.remove(key);
+		}
+
+		self.len -= 1;
+		Some(removed)
+	}
+
+	fn remove_all(&mut self, key: &K) -> usize {
+		let n = self.map.remove(key).map(|bucket| bucket.len()).unwrap_or(0);
+		self.len -= n;
+		n
+	}
+}
+
+/// Iterator over the values associated to a key in a [`MultiMap`].
+pub struct Values<'a, C: Iter + 'a> {
+	inner: Option<C::Iter<'a>>,
+}
+
+impl<'a, C: Iter + 'a> Iterator for Values<'a, C> {
+	type Item = C::ItemRef<'a>;
+
+	#[inline(always)]
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.as_mut()?.next()
+	}
+
+	#[inline(always)]
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		match &self.inner {
+			Some(inner) => inner.size_hint(),
+			None => (0, Some(0)),
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MultiMap, Unique};
+	use crate::{Clear, Len, MultiMap as _, MultiMapMut};
+	use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+	#[test]
+	fn empty_buckets_are_removed() {
+		let mut map: MultiMap<BTreeMap<u32, VecDeque<u32>>> = MultiMap::new();
+		map.insert(1, 10);
+		map.insert(1, 11);
+		map.insert(1, 10);
+
+		assert_eq!(map.remove_one(&1, &12), None);
+		assert_eq!(map.remove_one(&2, &10), None);
+		assert_eq!(map.len(), 3);
+
+		assert_eq!(map.remove_one(&1, &10), Some(10));
+		assert_eq!(map.get_all(&1).copied().collect::<Vec<_>>(), [11, 10]);
+		assert_eq!(map.remove_one(&1, &11), Some(11));
+		assert_eq!(map.remove_one(&1, &10), Some(10));
+
+		assert!(!map.contains_key(&1));
+		assert!(map.inner().is_empty());
+		assert!(map.is_empty());
+		assert_eq!(map.get_all(&1).size_hint(), (0, Some(0)));
+	}
+
+	#[test]
+	fn unique_values() {
+		let mut map: MultiMap<BTreeMap<u32, BTreeSet<u32>>, Unique> = MultiMap::new();
+		assert!(map.insert(1, 10));
+		assert!(!map.insert(1, 10));
+		assert!(map.insert(1, 11));
+		assert!(map.insert(2, 10));
+		assert_eq!((map.len_keys(), map.len_values()), (2, 3));
+
+		assert_eq!(map.remove_all(&1), 2);
+		assert_eq!(map.remove_all(&1), 0);
+		assert_eq!(map.len(), 1);
+
+		map.clear();
+		assert_eq!((map.len_keys(), map.len_values()), (0, 0));
+	}
+}
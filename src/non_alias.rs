@@ -34,12 +34,25 @@ pub trait Vec<T>: Stack<T> + Index<usize, Output = T> {}
 
 impl<T, C: Stack<T> + Index<usize, Output = T>> Vec<T> for C {}
 
+/// Mutable Vec data structure.
+///
+/// This trait alias describes the mutables operations on a Vec,
+/// including sorting (with the `alloc` feature).
+/// See [`Vec`] for more details.
+#[cfg(feature = "alloc")]
+pub trait VecMut<T>: Vec<T> + StackMut<T> + IndexMut<usize> + Sort {}
+
+#[cfg(feature = "alloc")]
+impl<T, C: Vec<T> + StackMut<T> + IndexMut<usize> + Sort> VecMut<T> for C {}
+
 /// Mutable Vec data structure.
 ///
 /// This trait alias describes the mutables operations on a Vec.
 /// See [`Vec`] for more details.
+#[cfg(not(feature = "alloc"))]
 pub trait VecMut<T>: Vec<T> + StackMut<T> + IndexMut<usize> {}
 
+#[cfg(not(feature = "alloc"))]
 impl<T, C: Vec<T> + StackMut<T> + IndexMut<usize>> VecMut<T> for C {}
 
 /// Immutable double-ended queue.
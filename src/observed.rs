@@ -0,0 +1,471 @@
+//! Change notification wrapper.
+use crate::{
+	Clear, Collection, Get, Insert, Len, MapInsert, PopBack, PopFront, PushBack, PushFront, Remove,
+	SimpleCollectionRef,
+};
+use core::{fmt, marker::PhantomData};
+
+/// Change notification sent by an [`Observed`] collection.
+///
+/// Each event carries the key (or index) of the modified binding, of type
+/// `K`, and the items involved.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Event<'a, K: ?Sized, T> {
+	/// A new item has been inserted in the collection with the given key.
+	Inserted(&'a K, &'a T),
+
+	/// An item has been inserted in the collection with the given key,
+	/// replacing an old item.
+	///
+	/// The first item is the old item, the second is the new item.
+	Replaced(&'a K, &'a T, &'a T),
+
+	/// The item with the given key has been removed from the collection.
+	Removed(&'a K, &'a T),
+
+	/// The collection has been cleared.
+	Cleared,
+}
+
+impl<'a, K: ?Sized, T> Clone for Event<'a, K, T> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, K: ?Sized, T> Copy for Event<'a, K, T> {}
+
+/// Listener of the changes made to an [`Observed`] collection.
+///
+/// The type parameter `K` is the type of keys given in the events.
+///
+/// This trait is implemented by every closure of the form
+/// `FnMut(&C, Event<K, C::Item>)`.
+pub trait Listener<C: Collection, K: ?Sized> {
+	/// Called after each modification of the collection.
+	///
+	/// The `collection` parameter gives access to the
+	/// collection in its new state.
+	fn notify(&mut self, collection: &C, event: Event<'_, K, C::Item>);
+}
+
+impl<C: Collection, K: ?Sized, F> Listener<C, K> for F
+where
+	F: FnMut(&C, Event<'_, K, C::Item>),
+{
+	#[inline(always)]
+	fn notify(&mut self, collection: &C, event: Event<'_, K, C::Item>) {
+		self(collection, event)
+	}
+}
+
+/// Collection notifying a [`Listener`] of every modification.
+///
+/// Every mutation trait ([`Insert`], [`MapInsert`], [`PushBack`],
+/// [`PushFront`], [`PopBack`], [`PopFront`], [`Remove`] and [`Clear`])
+/// is forwarded to the inner collection, and the listener is
+/// notified with the corresponding [`Event`] after the operation.
+/// Insertions are only notified when the length of the collection grew,
+/// so that an inner collection rejecting an item (such as a
+/// [`Bounded`](crate::Bounded) collection) or a set already containing it
+/// does not trigger an event.
+///
+/// The key type `K` of the events depends on the collection:
+///   - sequences ([`PushBack`], [`PushFront`], [`PopBack`], [`PopFront`] and
+///     `Remove<usize>`) use `usize` indexes;
+///   - maps ([`MapInsert<K>`] and `Remove<&K>`) use their keys;
+///   - sets ([`Insert`] and `Remove<&T>`) use the items themselves.
+///
+/// Immutable operations are forwarded as well.
+/// However traits giving mutable access to the items
+/// (such as [`GetMut`](crate::GetMut)) are not implemented, since
+/// modifications made through them could not be observed.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{observed::Event, MapInsert, Observed, Remove};
+/// use std::collections::HashMap;
+///
+/// let mut log = Vec::new();
+/// let mut map = Observed::new(HashMap::new(), |_: &HashMap<&str, i32>, event: Event<&str, i32>| {
+///   log.push(format!("{:?}", event))
+/// });
+///
+/// MapInsert::insert(&mut map, "a", 1);
+/// MapInsert::insert(&mut map, "a", 2);
+/// map.remove(&"a");
+/// map.remove(&"b");
+/// drop(map);
+///
+/// assert_eq!(log, [r#"Inserted("a", 1)"#, r#"Replaced("a", 1, 2)"#, r#"Removed("a", 2)"#]);
+/// ```
+pub struct Observed<C, L, K: ?Sized> {
+	inner: C,
+	listener: L,
+	key: PhantomData<fn(&K)>,
+}
+
+impl<C: Collection, L: Listener<C, K>, K: ?Sized> Observed<C, L, K> {
+	/// Wraps the given collection, notifying `listener` of every modification.
+	#[inline(always)]
+	pub fn new(inner: C, listener: L) -> Self {
+		Self {
+			inner,
+			listener,
+			key: PhantomData,
+		}
+	}
+}
+
+impl<C, L, K: ?Sized> Observed<C, L, K> {
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Returns a reference to the listener.
+	#[inline(always)]
+	pub fn listener(&self) -> &L {
+		&self.listener
+	}
+
+	/// Returns a mutable reference to the listener.
+	#[inline(always)]
+	pub fn listener_mut(&mut self) -> &mut L {
+		&mut self.listener
+	}
+
+	/// Consumes the wrapper and returns the inner collection and the listener.
+	#[inline(always)]
+	pub fn into_parts(self) -> (C, L) {
+		(self.inner, self.listener)
+	}
+
+	/// Notifies the listener that the given item has been removed, if any.
+	fn removed(&mut self, key: &K, item: Option<C::Item>) -> Option<C::Item>
+	where
+		C: Collection,
+		L: Listener<C, K>,
+	{
+		if let Some(item) = &item {
+			self.listener.notify(&self.inner, Event::Removed(key, item))
+		}
+
+		item
+	}
+}
+
+impl<C: Clone, L: Clone, K: ?Sized> Clone for Observed<C, L, K> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+			listener: self.listener.clone(),
+			key: PhantomData,
+		}
+	}
+}
+
+impl<C: fmt::Debug, L: fmt::Debug, K: ?Sized> fmt::Debug for Observed<C, L, K> {
+	fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+		f.debug_struct("Observed")
+			.field("inner", &self.inner)
+			.field("listener", &self.listener)
+			.finish()
+	}
+}
+
+impl<C: Default, L: Default, K: ?Sized> Default for Observed<C, L, K> {
+	#[inline(always)]
+	fn default() -> Self {
+		Self {
+			inner: C::default(),
+			listener: L::default(),
+			key: PhantomData,
+		}
+	}
+}
+
+crate::macros::forward_read!(Observed<C, L, K: ?Sized>.inner);
+
+impl<C, L> Insert for Observed<C, L, C::Item>
+where
+	C: Insert + Len + for<'a> Get<&'a <C as Collection>::Item>,
+	C::Item: Clone,
+	L: Listener<C, C::Item>,
+{
+	type Output = C::Output;
+
+	/// Inserts the given item, then notifies the listener if the
+	/// collection grew.
+	///
+	/// Items already present in the collection are forwarded without
+	/// notification. Other items are cloned to be given to the listener as
+	/// both the key and the new item.
+	#[inline(always)]
+	fn insert(&mut self, element: C::Item) -> C::Output {
+		if self.inner.contains(&element) {
+			return self.inner.insert(element);
+		}
+
+		let item = element.clone();
+		let len = self.inner.len();
+		let output = self.inner.insert(element);
+		if self.inner.len() > len {
+			self.listener
+				.notify(&self.inner, Event::Inserted(&item, &item));
+		}
+
+		output
+	}
+}
+
+impl<K, C, L> MapInsert<K> for Observed<C, L, K>
+where
+	K: Clone,
+	C: MapInsert<K, Output = Option<<C as Collection>::Item>>
+		+ for<'k> Get<&'k K>
+		+ SimpleCollectionRef,
+	L: Listener<C, K>,
+{
+	type Output = Option<C::Item>;
+
+	/// Inserts the given binding, then notifies the listener.
+	///
+	/// The key is cloned to be given to the listener.
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: C::Item) -> Option<C::Item> {
+		let k = key.clone();
+		let old = self.inner.insert(key, value);
+		if let Some(new) = self.inner.get(&k) {
+			let new = C::into_ref(new);
+			match &old {
+				Some(old) => self
+					.listener
+					.notify(&self.inner, Event::Replaced(&k, old, new)),
+				None => self.listener.notify(&self.inner, Event::Inserted(&k, new)),
+			}
+		}
+
+		old
+	}
+}
+
+impl<C, L> PushBack for Observed<C, L, usize>
+where
+	C: PushBack + Len + Get<usize>,
+	L: Listener<C, usize>,
+{
+	type Output = C::Output;
+
+	/// Pushes the given item, then notifies the listener if the
+	/// collection grew.
+	#[inline(always)]
+	fn push_back(&mut self, element: C::Item) -> C::Output {
+		let index = self.inner.len();
+		let output = self.inner.push_back(element);
+		if self.inner.len() > index {
+			if let Some(item) = self.inner.get(index) {
+				self.listener
+					.notify(&self.inner, Event::Inserted(&index, &*item))
+			}
+		}
+
+		output
+	}
+}
+
+impl<C, L> PushFront for Observed<C, L, usize>
+where
+	C: PushFront + Len + Get<usize>,
+	L: Listener<C, usize>,
+{
+	type Output = C::Output;
+
+	/// Pushes the given item, then notifies the listener if the
+	/// collection grew.
+	#[inline(always)]
+	fn push_front(&mut self, element: C::Item) -> C::Output {
+		let len = self.inner.len();
+		let output = self.inner.push_front(element);
+		if self.inner.len() > len {
+			if let Some(item) = self.inner.get(0) {
+				self.listener
+					.notify(&self.inner, Event::Inserted(&0, &*item))
+			}
+		}
+
+		output
+	}
+}
+
+impl<C: PopBack + Len, L: Listener<C, usize>> PopBack for Observed<C, L, usize> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<C::Item> {
+		let item = self.inner.pop_back();
+		let index = self.inner.len();
+		self.removed(&index, item)
+	}
+}
+
+impl<C: PopFront, L: Listener<C, usize>> PopFront for Observed<C, L, usize> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<C::Item> {
+		let item = self.inner.pop_front();
+		self.removed(&0, item)
+	}
+}
+
+impl<C: Remove<usize>, L: Listener<C, usize>> Remove<usize> for Observed<C, L, usize> {
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<C::Item> {
+		let item = self.inner.remove(index);
+		self.removed(&index, item)
+	}
+}
+
+impl<'a, Q: ?Sized, C: Remove<&'a Q>, L: Listener<C, Q>> Remove<&'a Q> for Observed<C, L, Q> {
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<C::Item> {
+		let item = self.inner.remove(key);
+		self.removed(key, item)
+	}
+}
+
+impl<C: Collection + Clear, L: Listener<C, K>, K: ?Sized> Clear for Observed<C, L, K> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.inner.clear();
+		self.listener.notify(&self.inner, Event::Cleared)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Event, Observed};
+	use crate::{
+		bounded::Reject, Bounded, Clear, Insert, MapInsert, PopBack, PopFront, PushBack, PushFront,
+		Remove,
+	};
+	use std::collections::{BTreeMap, BTreeSet, VecDeque};
+
+	#[test]
+	fn sequence_events_carry_indexes() {
+		let mut log = Vec::new();
+		let mut seq = Observed::new(
+			VecDeque::new(),
+			|_: &VecDeque<char>, event: Event<usize, char>| log.push(format!("{:?}", event)),
+		);
+
+		seq.push_back('a');
+		seq.push_back('b');
+		seq.push_front('c');
+		seq.pop_back();
+		seq.remove(0);
+		seq.pop_front();
+		seq.pop_front();
+		seq.clear();
+		drop(seq);
+
+		assert_eq!(
+			log,
+			[
+				"Inserted(0, 'a')",
+				"Inserted(1, 'b')",
+				"Inserted(0, 'c')",
+				"Removed(2, 'b')",
+				"Removed(0, 'c')",
+				"Removed(0, 'a')",
+				"Cleared"
+			]
+		);
+	}
+
+	#[test]
+	fn map_events_carry_keys() {
+		let mut log = Vec::new();
+		let mut map = Observed::new(
+			BTreeMap::new(),
+			|map: &BTreeMap<String, i32>, event: Event<String, i32>| {
+				log.push(format!("{:?} {}", event, map.len()))
+			},
+		);
+
+		map.insert("a".into(), 1);
+		map.insert("a".into(), 2);
+		map.remove(&String::from("b"));
+		map.remove(&String::from("a"));
+		drop(map);
+
+		assert_eq!(
+			log,
+			[
+				r#"Inserted("a", 1) 1"#,
+				r#"Replaced("a", 1, 2) 1"#,
+				r#"Removed("a", 2) 0"#
+			]
+		);
+	}
+
+	#[test]
+	fn set_events_carry_items() {
+		let mut log = Vec::new();
+		let mut set = Observed::new(
+			BTreeSet::new(),
+			|_: &BTreeSet<i32>, event: Event<i32, i32>| log.push(format!("{:?}", event)),
+		);
+
+		Insert::insert(&mut set, 1);
+		set.remove(&1);
+		set.remove(&2);
+		drop(set);
+
+		assert_eq!(log, ["Inserted(1, 1)", "Removed(1, 1)"]);
+	}
+
+	#[test]
+	fn rejected_pushes_are_not_notified() {
+		let mut log = Vec::new();
+		let mut seq = Observed::new(
+			Bounded::with_policy(VecDeque::new(), 0, Reject),
+			|_: &Bounded<VecDeque<char>, Reject>, event: Event<usize, char>| {
+				log.push(format!("{:?}", event))
+			},
+		);
+
+		assert_eq!(seq.push_back('a'), Err('a'));
+		drop(seq);
+		assert!(log.is_empty());
+
+		let mut log = Vec::new();
+		let mut seq = Observed::new(
+			Bounded::with_policy(VecDeque::new(), 1, Reject),
+			|_: &Bounded<VecDeque<char>, Reject>, event: Event<usize, char>| {
+				log.push(format!("{:?}", event))
+			},
+		);
+
+		assert!(seq.push_back('a').is_ok());
+		assert_eq!(seq.push_back('b'), Err('b'));
+		drop(seq);
+		assert_eq!(log, ["Inserted(0, 'a')"]);
+	}
+
+	#[test]
+	fn present_set_items_are_not_notified() {
+		let mut log = Vec::new();
+		let mut set = Observed::new(
+			BTreeSet::new(),
+			|_: &BTreeSet<i32>, event: Event<i32, i32>| log.push(format!("{:?}", event)),
+		);
+
+		assert!(Insert::insert(&mut set, 1));
+		assert!(!Insert::insert(&mut set, 1));
+		drop(set);
+
+		assert_eq!(log, ["Inserted(1, 1)"]);
+	}
+}
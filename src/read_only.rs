@@ -0,0 +1,236 @@
+use crate::{Collection, CollectionRef, Get, GetKeyValue, Iter, Keyed, KeyedRef, Len, MapIter};
+use core::ops::Index;
+
+/// Read-only view over a collection.
+///
+/// This wrapper only forwards the immutable operations of the inner
+/// collection ([`Collection`], [`CollectionRef`], [`Keyed`], [`KeyedRef`],
+/// [`Len`], [`Get`], [`GetKeyValue`], [`Iter`] and [`MapIter`]).
+/// The [`Index`] operator is forwarded as well: it is not a trait of this
+/// crate, but the [`Vec`](crate::Vec) alias requires it, so a view over a
+/// vector could not be a `Vec<T>` without it. [`IndexMut`](core::ops::IndexMut)
+/// is not forwarded.
+/// It can be used to give a collection to a consumer that must not mutate it,
+/// without revealing its concrete type.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Map, ReadOnly, ReadOnlyRef, Vec};
+/// use std::collections::HashMap;
+///
+/// fn read<M: Map<&'static str, i32>>(map: &M) -> Option<i32> {
+///   map.get(&"a").map(|v| *v)
+/// }
+///
+/// fn last<V: Vec<i32>>(vec: &V) -> Option<i32> {
+///   vec.back().map(|v| *v)
+/// }
+///
+/// let mut map = HashMap::new();
+/// map.insert("a", 1);
+///
+/// let view = ReadOnly::new(map);
+/// assert_eq!(read(&view), Some(1));
+///
+/// let vec = vec![1, 2, 3];
+/// assert_eq!(last(&ReadOnlyRef::new(&vec)), Some(3));
+/// ```
+///
+/// Mutable operations are not available through the view:
+///
+/// ```compile_fail
+/// use cc_traits::{MapMut, ReadOnly};
+/// use std::collections::HashMap;
+///
+/// fn write<M: MapMut<&'static str, i32>>(map: &mut M) {
+///   map.insert("a", 1);
+/// }
+///
+/// let mut view = ReadOnly::new(HashMap::new());
+/// write(&mut view);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct ReadOnly<C> {
+	inner: C,
+}
+
+impl<C> ReadOnly<C> {
+	/// Creates a new read-only view owning the given collection.
+	#[inline(always)]
+	pub fn new(inner: C) -> Self {
+		Self { inner }
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Consumes the view and returns the inner collection.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+}
+
+impl<C> From<C> for ReadOnly<C> {
+	#[inline(always)]
+	fn from(inner: C) -> Self {
+		Self::new(inner)
+	}
+}
+
+crate::macros::forward_read!(ReadOnly<C>.inner);
+
+/// Borrowing read-only view over a collection.
+///
+/// This is the borrowing counterpart of [`ReadOnly`].
+#[derive(Debug, PartialEq, Eq, Hash)]
+pub struct ReadOnlyRef<'a, C> {
+	inner: &'a C,
+}
+
+impl<'a, C> ReadOnlyRef<'a, C> {
+	/// Creates a new read-only view borrowing the given collection.
+	#[inline(always)]
+	pub fn new(inner: &'a C) -> Self {
+		Self { inner }
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &'a C {
+		self.inner
+	}
+}
+
+impl<'a, C> Clone for ReadOnlyRef<'a, C> {
+	#[inline(always)]
+	fn clone(&self) -> Self {
+		*self
+	}
+}
+
+impl<'a, C> Copy for ReadOnlyRef<'a, C> {}
+
+impl<'a, C> From<&'a C> for ReadOnlyRef<'a, C> {
+	#[inline(always)]
+	fn from(inner: &'a C) -> Self {
+		Self::new(inner)
+	}
+}
+
+impl<'b, C: Collection> Collection for ReadOnlyRef<'b, C> {
+	type Item = C::Item;
+}
+
+impl<'b, C: CollectionRef> CollectionRef for ReadOnlyRef<'b, C> {
+	type ItemRef<'a> = C::ItemRef<'b> where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<'b, C: Keyed> Keyed for ReadOnlyRef<'b, C> {
+	type Key = C::Key;
+}
+
+impl<'b, C: KeyedRef> KeyedRef for ReadOnlyRef<'b, C> {
+	type KeyRef<'a> = C::KeyRef<'b> where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<'b, C: Len> Len for ReadOnlyRef<'b, C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+}
+
+impl<'b, T, C: Get<T>> Get<T> for ReadOnlyRef<'b, C> {
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<Self::ItemRef<'_>> {
+		self.inner.get(key)
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: T) -> bool {
+		self.inner.contains(key)
+	}
+}
+
+impl<'b, T, C: GetKeyValue<T>> GetKeyValue<T> for ReadOnlyRef<'b, C> {
+	#[inline(always)]
+	fn get_key_value(&self, key: T) -> Option<(Self::KeyRef<'_>, Self::ItemRef<'_>)> {
+		self.inner.get_key_value(key)
+	}
+}
+
+impl<'b, C: Iter> Iter for ReadOnlyRef<'b, C> {
+	type Iter<'a> = C::Iter<'b> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		Iter::iter(self.inner)
+	}
+}
+
+impl<'b, C: MapIter> MapIter for ReadOnlyRef<'b, C> {
+	type Iter<'a> = C::Iter<'b> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		MapIter::iter(self.inner)
+	}
+}
+
+impl<'b, I, C: Index<I>> Index<I> for ReadOnlyRef<'b, C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn index(&self, index: I) -> &C::Output {
+		&self.inner[index]
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{ReadOnly, ReadOnlyRef};
+	use crate::{Get, GetKeyValue, Iter, Len, MapIter};
+	use std::collections::BTreeMap;
+
+	#[test]
+	fn forwards_map_reads() {
+		let map: BTreeMap<_, _> = [("a", 1), ("b", 2)].iter().copied().collect();
+		let view = ReadOnly::new(map);
+
+		assert_eq!(view.len(), 2);
+		assert_eq!(view.get(&"b"), Some(&2));
+		assert!(!view.contains(&"c"));
+		assert_eq!(view.get_key_value(&"a"), Some((&"a", &1)));
+		assert_eq!(MapIter::iter(&view).count(), 2);
+		assert_eq!(view["a"], 1);
+		assert_eq!(view.into_inner().len(), 2);
+	}
+
+	#[test]
+	fn borrowed_items_outlive_the_view() {
+		let vec = vec![1, 2, 3];
+		let item = {
+			let view = ReadOnlyRef::new(&vec);
+			view.get(1)
+		};
+		assert_eq!(item, Some(&2));
+
+		let sum: i32 = Iter::iter(&ReadOnlyRef::from(&vec)).sum();
+		assert_eq!(sum, 6);
+		assert!(ReadOnlyRef::new(&Vec::<i32>::new()).is_empty());
+	}
+}
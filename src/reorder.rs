@@ -0,0 +1,430 @@
+use crate::{Collection, Len};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+
+/// Sequence exposing its items as a contiguous mutable slice.
+///
+/// Every type implementing this trait (and [`Len`]) implements the
+/// reordering traits ([`Swap`], [`Reverse`], [`Rotate`] and [`Sort`])
+/// through the slice methods.
+pub trait ContiguousMut: Collection {
+	/// Returns the items of the sequence, in order, as a mutable slice.
+	fn as_mut_slice(&mut self) -> &mut [Self::Item];
+}
+
+/// Sequence whose items can be swapped.
+pub trait Swap {
+	/// Swap the items at index `i` and `j`.
+	///
+	/// Panics if `i` or `j` are out of bounds.
+	fn swap(&mut self, i: usize, j: usize);
+}
+
+/// Reverse the order of the items in the given range, by swapping them.
+fn reverse_range<C: Swap + ?Sized>(sequence: &mut C, mut start: usize, mut end: usize) {
+	while start + 1 < end {
+		end -= 1;
+		sequence.swap(start, end);
+		start += 1;
+	}
+}
+
+/// Sequence whose items can be reversed in place.
+///
+/// The provided method only relies on [`Swap`], so that sequences without
+/// contiguous storage (such as bit vectors returning proxy references) can
+/// implement this trait with an empty `impl` block.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Len, Reverse, Rotate, Swap};
+///
+/// #[derive(Debug, PartialEq)]
+/// struct Bits(u8, usize);
+///
+/// impl Len for Bits {
+///   fn len(&self) -> usize { self.1 }
+/// }
+///
+/// impl Swap for Bits {
+///   fn swap(&mut self, i: usize, j: usize) {
+///     assert!(i < self.1 && j < self.1, "index out of bounds");
+///     let (a, b) = ((self.0 >> i) & 1, (self.0 >> j) & 1);
+///     self.0 ^= (a ^ b) << i | (a ^ b) << j;
+///   }
+/// }
+///
+/// impl Reverse for Bits {}
+/// impl Rotate for Bits {}
+///
+/// let mut bits = Bits(0b001, 3);
+/// bits.reverse();
+/// assert_eq!(bits, Bits(0b100, 3));
+/// bits.rotate_left(1);
+/// assert_eq!(bits, Bits(0b010, 3));
+/// ```
+pub trait Reverse: Swap + Len {
+	/// Reverse the order of the items.
+	#[inline(always)]
+	fn reverse(&mut self) {
+		let len = self.len();
+		reverse_range(self, 0, len)
+	}
+}
+
+/// Sequence whose items can be rotated in place.
+///
+/// The provided methods only rely on [`Swap`], performing a linear number
+/// of swaps.
+pub trait Rotate: Swap + Len {
+	/// Rotate the sequence in place such that the first `mid` items move to
+	/// the end.
+	///
+	/// Panics if `mid` is greater than the length of the sequence.
+	fn rotate_left(&mut self, mid: usize) {
+		let len = self.len();
+		assert!(mid <= len, "`mid` out of bounds");
+		reverse_range(self, 0, mid);
+		reverse_range(self, mid, len);
+		reverse_range(self, 0, len)
+	}
+
+	/// Rotate the sequence in place such that the last `k` items move to
+	/// the front.
+	///
+	/// Panics if `k` is greater than the length of the sequence.
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		let len = self.len();
+		assert!(k <= len, "`k` out of bounds");
+		self.rotate_left(len - k)
+	}
+}
+
+/// Sequence that can be sorted in place.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Sort, VecMut};
+///
+/// fn sort_desc<V: VecMut<i32> + Sort>(v: &mut V) {
+///   v.sort_by(|a, b| b.cmp(a))
+/// }
+///
+/// let mut v = vec![2, 3, 1];
+/// sort_desc(&mut v);
+/// assert_eq!(v, [3, 2, 1]);
+/// ```
+#[cfg(feature = "alloc")]
+pub trait Sort: Collection {
+	/// Sort the sequence.
+	///
+	/// This sort is stable.
+	fn sort(&mut self)
+	where
+		Self::Item: Ord;
+
+	/// Sort the sequence with the given comparison function.
+	///
+	/// This sort is stable.
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&Self::Item, &Self::Item) -> Ordering;
+
+	/// Sort the sequence with the given key extraction function.
+	///
+	/// This sort is unstable.
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&Self::Item) -> K;
+}
+
+impl<C: ContiguousMut> Swap for C {
+	#[inline(always)]
+	fn swap(&mut self, i: usize, j: usize) {
+		self.as_mut_slice().swap(i, j)
+	}
+}
+
+impl<C: ContiguousMut + Len> Reverse for C {
+	#[inline(always)]
+	fn reverse(&mut self) {
+		self.as_mut_slice().reverse()
+	}
+}
+
+impl<C: ContiguousMut + Len> Rotate for C {
+	#[inline(always)]
+	fn rotate_left(&mut self, mid: usize) {
+		self.as_mut_slice().rotate_left(mid)
+	}
+
+	#[inline(always)]
+	fn rotate_right(&mut self, k: usize) {
+		self.as_mut_slice().rotate_right(k)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C: ContiguousMut> Sort for C {
+	#[inline(always)]
+	fn sort(&mut self)
+	where
+		C::Item: Ord,
+	{
+		self.as_mut_slice().sort()
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&C::Item, &C::Item) -> Ordering,
+	{
+		self.as_mut_slice().sort_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&C::Item) -> K,
+	{
+		self.as_mut_slice().sort_unstable_by_key(f)
+	}
+}
+
+/// Reordering operations for sequences whose items can be moved out.
+///
+/// Those functions only rely on [`GetMut<usize>`](crate::GetMut) and
+/// [`Len`], accessing the items one at a time.
+/// Since two items cannot be borrowed mutably at the same time,
+/// they are moved out of the sequence, leaving a default value behind
+/// for the duration of the operation.
+/// They can be used to implement [`Swap`] and
+/// [`Sort`] for sequences whose items are not stored
+/// contiguously.
+///
+/// ## Scope
+///
+/// The items must implement [`Default`], and are written back through the
+/// [`DerefMut`](core::ops::DerefMut) implementation of
+/// [`CollectionMut::ItemMut`](crate::CollectionMut::ItemMut).
+/// Proxy item references that do not store the written value in the
+/// sequence (at the latest when dropped) are not supported:
+/// such sequences must implement the reordering traits natively.
+///
+/// ## Panics
+///
+/// The functions panic if [`GetMut::get_mut`](crate::GetMut::get_mut)
+/// returns `None` for one of the indexes given to `swap`, or for an index
+/// lower than the length of the sequence.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{reorder::indexed, Reverse, Swap};
+/// # use cc_traits::{Collection, CollectionRef, CollectionMut, Get, GetMut, Len};
+/// # use std::collections::LinkedList;
+/// # #[derive(Debug, PartialEq)]
+/// # struct List(LinkedList<i32>);
+/// # impl Collection for List { type Item = i32; }
+/// # impl CollectionRef for List {
+/// #   type ItemRef<'a> = &'a i32 where Self: 'a;
+/// #   cc_traits::covariant_item_ref!();
+/// # }
+/// # impl CollectionMut for List {
+/// #   type ItemMut<'a> = &'a mut i32 where Self: 'a;
+/// #   cc_traits::covariant_item_mut!();
+/// # }
+/// # impl Len for List { fn len(&self) -> usize { self.0.len() } }
+/// # impl Get<usize> for List { fn get(&self, i: usize) -> Option<&i32> { self.0.iter().nth(i) } }
+/// # impl GetMut<usize> for List { fn get_mut(&mut self, i: usize) -> Option<&mut i32> { self.0.iter_mut().nth(i) } }
+///
+/// impl Swap for List {
+///   fn swap(&mut self, i: usize, j: usize) {
+///     indexed::swap(self, i, j)
+///   }
+/// }
+///
+/// impl Reverse for List {}
+///
+/// let mut list = List(vec![1, 2, 3].into_iter().collect());
+/// list.reverse();
+/// assert_eq!(list, List(vec![3, 2, 1].into_iter().collect()));
+/// ```
+pub mod indexed {
+	use crate::GetMut;
+	#[cfg(feature = "alloc")]
+	use crate::Len;
+	#[cfg(feature = "alloc")]
+	use alloc::vec::Vec;
+	#[cfg(feature = "alloc")]
+	use core::cmp::Ordering;
+
+	/// Returns a mutable reference to the item at the given index.
+	///
+	/// Panics if the index is out of bounds.
+	#[inline(always)]
+	fn item_mut<C: GetMut<usize>>(sequence: &mut C, i: usize) -> C::ItemMut<'_> {
+		match sequence.get_mut(i) {
+			Some(item) => item,
+			None => panic!("index out of bounds"),
+		}
+	}
+
+	/// Swap the items at index `i` and `j`.
+	///
+	/// Panics if `i` or `j` are out of bounds.
+	pub fn swap<C>(sequence: &mut C, i: usize, j: usize)
+	where
+		C: GetMut<usize>,
+		C::Item: Default,
+	{
+		if i != j {
+			let a = core::mem::take(&mut *item_mut(sequence, i));
+			let b = core::mem::replace(&mut *item_mut(sequence, j), a);
+			*item_mut(sequence, i) = b
+		}
+	}
+
+	/// Moves the items out of the sequence, applies `f` to them, and moves
+	/// them back in.
+	#[cfg(feature = "alloc")]
+	fn with_items<C, F>(sequence: &mut C, f: F)
+	where
+		C: GetMut<usize> + Len,
+		C::Item: Default,
+		F: FnOnce(&mut [C::Item]),
+	{
+		let mut items: Vec<C::Item> = (0..sequence.len())
+			.map(|i| core::mem::take(&mut *item_mut(sequence, i)))
+			.collect();
+
+		f(&mut items);
+
+		for (i, item) in items.into_iter().enumerate() {
+			*item_mut(sequence, i) = item
+		}
+	}
+
+	/// Sort the sequence.
+	///
+	/// This sort is stable.
+	#[cfg(feature = "alloc")]
+	#[inline(always)]
+	pub fn sort<C>(sequence: &mut C)
+	where
+		C: GetMut<usize> + Len,
+		C::Item: Default + Ord,
+	{
+		with_items(sequence, |items| items.sort())
+	}
+
+	/// Sort the sequence with the given comparison function.
+	///
+	/// This sort is stable.
+	#[cfg(feature = "alloc")]
+	#[inline(always)]
+	pub fn sort_by<C, F>(sequence: &mut C, compare: F)
+	where
+		C: GetMut<usize> + Len,
+		C::Item: Default,
+		F: FnMut(&C::Item, &C::Item) -> Ordering,
+	{
+		with_items(sequence, |items| items.sort_by(compare))
+	}
+
+	/// Sort the sequence with the given key extraction function.
+	///
+	/// This sort is unstable.
+	#[cfg(feature = "alloc")]
+	#[inline(always)]
+	pub fn sort_unstable_by_key<C, K, F>(sequence: &mut C, f: F)
+	where
+		C: GetMut<usize> + Len,
+		C::Item: Default,
+		K: Ord,
+		F: FnMut(&C::Item) -> K,
+	{
+		with_items(sequence, |items| items.sort_unstable_by_key(f))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Reverse, Rotate, Sort, Swap};
+	use crate::{Ring, Transactional, VecMut, VecUndo};
+	use std::collections::VecDeque;
+
+	/// Sorts the given `Vec`-like sequence in descending order.
+	fn sort_desc<V: VecMut<i32>>(v: &mut V) {
+		v.sort_by(|a, b| b.cmp(a))
+	}
+
+	/// Deque whose items wrap around the end of its buffer.
+	fn wrapped() -> VecDeque<i32> {
+		let mut deque = VecDeque::with_capacity(4);
+		deque.extend([0, 0, 1, 2].iter().copied());
+		deque.pop_front();
+		deque.pop_front();
+		deque.push_back(3);
+		deque.push_back(4);
+		deque
+	}
+
+	#[test]
+	fn deque_reorders_without_contiguous_storage() {
+		let mut deque = wrapped();
+		Reverse::reverse(&mut deque);
+		assert_eq!(deque, [4, 3, 2, 1]);
+		Rotate::rotate_left(&mut deque, 1);
+		assert_eq!(deque, [3, 2, 1, 4]);
+		Rotate::rotate_right(&mut deque, 4);
+		assert_eq!(deque, [3, 2, 1, 4]);
+		Swap::swap(&mut deque, 0, 3);
+		assert_eq!(deque, [4, 2, 1, 3]);
+		Sort::sort(&mut deque);
+		assert_eq!(deque, [1, 2, 3, 4]);
+	}
+
+	#[test]
+	fn vec_mut_sorts() {
+		let mut v = vec![2, 3, 1];
+		sort_desc(&mut v);
+		assert_eq!(v, [3, 2, 1]);
+
+		let mut deque = wrapped();
+		sort_desc(&mut deque);
+		assert_eq!(deque, [4, 3, 2, 1]);
+
+		let mut ring = Ring::new(wrapped(), 4);
+		sort_desc(&mut ring);
+		assert_eq!(ring.inner(), &[4, 3, 2, 1]);
+
+		let mut v: Transactional<Vec<i32>, VecUndo<i32>> = Transactional::new(vec![2, 3, 1]);
+		sort_desc(&mut v);
+		assert_eq!(v.inner(), &[3, 2, 1]);
+	}
+
+	#[test]
+	fn reverse_empty_and_single() {
+		let mut v: Vec<i32> = Vec::new();
+		Reverse::reverse(&mut v);
+		assert!(v.is_empty());
+
+		let mut deque = VecDeque::from(vec![1]);
+		Reverse::reverse(&mut deque);
+		Rotate::rotate_left(&mut deque, 1);
+		assert_eq!(deque, [1]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn rotate_out_of_bounds() {
+		let mut deque = wrapped();
+		Rotate::rotate_left(&mut deque, 5);
+	}
+}
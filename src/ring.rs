@@ -0,0 +1,294 @@
+#[cfg(feature = "alloc")]
+use crate::Sort;
+use crate::{
+	Capacity, Clear, CollectionMut, GetMut, IterMut, Len, PopBack, PopFront, PushBack, PushFront,
+	WithCapacity,
+};
+#[cfg(feature = "alloc")]
+use core::cmp::Ordering;
+use core::ops::IndexMut;
+
+/// Ring buffer, overwriting its oldest item when full.
+///
+/// This wraps any double-ended queue `C`
+/// (such as a [`DequeMut<T>`](crate::DequeMut)) with a fixed capacity.
+/// When the buffer is full, [`PushBack::push_back`] removes the front
+/// (oldest) item to make room for the new one, and returns it.
+/// Symmetrically, [`PushFront::push_front`] evicts the back (newest) item.
+///
+/// Indexed access ([`Get<usize>`](crate::Get), [`GetMut<usize>`] and
+/// [`Index<usize>`](core::ops::Index)) is relative to the logical start
+/// of the buffer: index `0` is the oldest item.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, PushBack, Ring};
+/// use std::collections::VecDeque;
+///
+/// let mut window: Ring<VecDeque<i32>> = Ring::with_capacity(3);
+/// assert_eq!(window.push_back(1), None);
+/// assert_eq!(window.push_back(2), None);
+/// assert_eq!(window.push_back(3), None);
+/// assert_eq!(window.push_back(4), Some(1));
+///
+/// assert_eq!(window.get(0), Some(&2));
+/// assert_eq!(window[2], 4);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ring<C> {
+	inner: C,
+	capacity: usize,
+}
+
+impl<C> Ring<C> {
+	/// Creates a new ring buffer from the given collection,
+	/// holding at most `capacity` items.
+	///
+	/// If the collection holds more than `capacity` items,
+	/// the oldest items are removed.
+	#[inline(always)]
+	pub fn new(inner: C, capacity: usize) -> Self
+	where
+		C: Len + PopFront,
+	{
+		let mut ring = Self { inner, capacity };
+		ring.truncate();
+		ring
+	}
+
+	/// Creates a new empty ring buffer with the given capacity.
+	#[inline(always)]
+	pub fn with_capacity(capacity: usize) -> Self
+	where
+		C: WithCapacity,
+	{
+		Self {
+			inner: C::with_capacity(capacity),
+			capacity,
+		}
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Consumes the ring buffer and returns the inner collection.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+
+	/// Checks if the buffer is full,
+	/// in which case the next push will evict the oldest item.
+	#[inline(always)]
+	pub fn is_full(&self) -> bool
+	where
+		C: Len,
+	{
+		self.inner.len() >= self.capacity
+	}
+
+	/// Removes the oldest items until the buffer fits its capacity.
+	fn truncate(&mut self)
+	where
+		C: Len + PopFront,
+	{
+		while self.inner.len() > self.capacity {
+			self.inner.pop_front();
+		}
+	}
+}
+
+crate::macros::forward_read!(Ring<C>.inner);
+
+impl<C> Capacity for Ring<C> {
+	#[inline(always)]
+	fn capacity(&self) -> usize {
+		self.capacity
+	}
+}
+
+impl<C: CollectionMut> CollectionMut for Ring<C> {
+	type ItemMut<'a> = C::ItemMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<Q, C: GetMut<Q>> GetMut<Q> for Ring<C> {
+	#[inline(always)]
+	fn get_mut(&mut self, key: Q) -> Option<Self::ItemMut<'_>> {
+		self.inner.get_mut(key)
+	}
+}
+
+impl<I, C: IndexMut<I>> IndexMut<I> for Ring<C> {
+	#[inline(always)]
+	fn index_mut(&mut self, index: I) -> &mut C::Output {
+		self.inner.index_mut(index)
+	}
+}
+
+#[cfg(feature = "alloc")]
+impl<C: Sort> Sort for Ring<C> {
+	#[inline(always)]
+	fn sort(&mut self)
+	where
+		C::Item: Ord,
+	{
+		self.inner.sort()
+	}
+
+	#[inline(always)]
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&C::Item, &C::Item) -> Ordering,
+	{
+		self.inner.sort_by(compare)
+	}
+
+	#[inline(always)]
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&C::Item) -> K,
+	{
+		self.inner.sort_unstable_by_key(f)
+	}
+}
+
+impl<C: IterMut> IterMut for Ring<C> {
+	type IterMut<'a> = C::IterMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		self.inner.iter_mut()
+	}
+}
+
+impl<C: Len + PushBack + PopFront> PushBack for Ring<C> {
+	type Output = Option<C::Item>;
+
+	/// Pushes the given item at the back of the buffer.
+	///
+	/// Returns the oldest item if it has been evicted to make room.
+	/// If the capacity of the buffer is zero, the given item is
+	/// immediately returned.
+	#[inline(always)]
+	fn push_back(&mut self, item: C::Item) -> Option<C::Item> {
+		if self.capacity == 0 {
+			return Some(item);
+		}
+
+		let evicted = if self.is_full() {
+			self.inner.pop_front()
+		} else {
+			None
+		};
+
+		self.inner.push_back(item);
+		evicted
+	}
+}
+
+impl<C: Len + PushFront + PopBack> PushFront for Ring<C> {
+	type Output = Option<C::Item>;
+
+	/// Pushes the given item at the front of the buffer.
+	///
+	/// Returns the newest item if it has been evicted to make room.
+	/// If the capacity of the buffer is zero, the given item is
+	/// immediately returned.
+	#[inline(always)]
+	fn push_front(&mut self, item: C::Item) -> Option<C::Item> {
+		if self.capacity == 0 {
+			return Some(item);
+		}
+
+		let evicted = if self.is_full() {
+			self.inner.pop_back()
+		} else {
+			None
+		};
+
+		self.inner.push_front(item);
+		evicted
+	}
+}
+
+impl<C: PopBack> PopBack for Ring<C> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<C::Item> {
+		self.inner.pop_back()
+	}
+}
+
+impl<C: PopFront> PopFront for Ring<C> {
+	#[inline(always)]
+	fn pop_front(&mut self) -> Option<C::Item> {
+		self.inner.pop_front()
+	}
+}
+
+impl<C: Clear> Clear for Ring<C> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.inner.clear()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Ring;
+	use crate::{Capacity, Get, GetMut, Len, PopBack, PopFront, PushBack, PushFront};
+	use std::collections::VecDeque;
+
+	#[test]
+	fn explicit_capacity() {
+		let mut ring = Ring::new(VecDeque::new(), 2);
+		assert_eq!(ring.capacity(), 2);
+		assert_eq!(ring.push_back(1), None);
+		assert_eq!(ring.push_back(2), None);
+		assert!(ring.is_full());
+		assert_eq!(ring.push_back(3), Some(1));
+		assert_eq!(ring.inner(), &[2, 3]);
+	}
+
+	#[test]
+	fn overfull_construction() {
+		let ring = Ring::new(VecDeque::from(vec![1, 2, 3, 4]), 2);
+		assert_eq!(ring.len(), 2);
+		assert_eq!(ring.get(0), Some(&3));
+	}
+
+	#[test]
+	fn zero_capacity() {
+		let mut ring: Ring<VecDeque<i32>> = Ring::with_capacity(0);
+		assert_eq!(ring.push_back(1), Some(1));
+		assert_eq!(ring.push_front(2), Some(2));
+		assert!(ring.is_empty());
+		assert!(ring.is_full());
+	}
+
+	#[test]
+	fn push_front_evicts_the_newest_item() {
+		let mut ring: Ring<VecDeque<i32>> = Ring::with_capacity(2);
+		ring.push_back(1);
+		ring.push_back(2);
+		assert_eq!(ring.push_front(0), Some(2));
+		*ring.get_mut(1).unwrap() += 10;
+		assert_eq!(ring.inner(), &[0, 11]);
+
+		assert_eq!(ring.pop_front(), Some(0));
+		assert_eq!(ring.pop_back(), Some(11));
+		assert!(!ring.is_full());
+	}
+}
@@ -0,0 +1,394 @@
+use crate::{
+	BackMut, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed,
+	KeyedRef, Len, MapInsert, MapIter, PopBack, PushBack, Remove, SimpleCollectionMut,
+	SimpleCollectionRef,
+};
+use core::marker::PhantomData;
+
+/// Layered map, where each layer (or scope) can shadow the bindings
+/// of the outer layers.
+///
+/// The layers are maps `M` stored in a stack `S`
+/// (such as a [`StackMut<M>`](crate::StackMut) providing [`Get<usize>`]),
+/// from the outermost (global) scope to the innermost scope.
+/// Lookups ([`Get`], [`GetKeyValue`] and [`GetMut`]) search the layers from
+/// the innermost to the outermost and return the visible binding,
+/// so that a scoped map is a [`Map<K, V>`](crate::Map).
+/// The [`Len`] of a scoped map is its number of visible bindings.
+///
+/// Modifications ([`ScopedMap::insert`] and [`ScopedMap::remove`]) only
+/// affect the innermost scope: removing a binding makes the shadowed
+/// binding of an outer scope visible again.
+/// For this reason a scoped map does not implement [`MapInsert`] and
+/// [`Remove`], and is not a [`MapMut<K, V>`](crate::MapMut).
+///
+/// The global scope always exists: [`ScopedMap::pop_scope`] never removes it.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, ScopedMap};
+/// use std::collections::HashMap;
+///
+/// let mut env: ScopedMap<Vec<HashMap<&str, i32>>, _> = ScopedMap::new();
+/// env.insert("x", 1);
+/// env.insert("y", 2);
+///
+/// env.push_scope();
+/// env.insert("x", 3);
+/// assert_eq!(env.get(&"x"), Some(&3));
+/// assert_eq!(env.get(&"y"), Some(&2));
+///
+/// env.remove(&"y");
+/// assert_eq!(env.get(&"y"), Some(&2));
+///
+/// env.pop_scope();
+/// assert_eq!(env.get(&"x"), Some(&1));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct ScopedMap<S, M> {
+	layers: S,
+	layer: PhantomData<M>,
+}
+
+impl<S, M> Default for ScopedMap<S, M>
+where
+	S: Default + PushBack<Item = M>,
+	M: Default,
+{
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<S, M> ScopedMap<S, M> {
+	/// Creates a new scoped map with an empty global scope.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		S: Default + PushBack<Item = M>,
+		M: Default,
+	{
+		let mut layers = S::default();
+		layers.push_back(M::default());
+		Self {
+			layers,
+			layer: PhantomData,
+		}
+	}
+
+	/// Returns a reference to the stack of layers, from the outermost
+	/// to the innermost scope.
+	#[inline(always)]
+	pub fn layers(&self) -> &S {
+		&self.layers
+	}
+
+	/// Consumes the scoped map and returns the stack of layers.
+	#[inline(always)]
+	pub fn into_layers(self) -> S {
+		self.layers
+	}
+
+	/// Returns the number of scopes, including the global scope.
+	#[inline(always)]
+	pub fn depth(&self) -> usize
+	where
+		S: Len,
+	{
+		self.layers.len()
+	}
+
+	/// Opens a new empty innermost scope.
+	#[inline(always)]
+	pub fn push_scope(&mut self)
+	where
+		S: PushBack<Item = M>,
+		M: Default,
+	{
+		self.layers.push_back(M::default());
+	}
+
+	/// Closes the innermost scope, discarding its bindings.
+	///
+	/// Returns the bindings of the closed scope, or `None` if only the
+	/// global scope remains.
+	#[inline(always)]
+	pub fn pop_scope(&mut self) -> Option<M>
+	where
+		S: Len + PopBack<Item = M>,
+	{
+		if self.layers.len() > 1 {
+			self.layers.pop_back()
+		} else {
+			None
+		}
+	}
+
+	/// Inserts a binding in the innermost scope,
+	/// shadowing any binding of the same key in the outer scopes.
+	///
+	/// Returns the output of the innermost layer insertion, which only
+	/// reflects the bindings of the innermost scope.
+	#[inline(always)]
+	pub fn insert<K>(&mut self, key: K, value: M::Item) -> M::Output
+	where
+		S: Collection<Item = M> + BackMut + SimpleCollectionMut,
+		M: MapInsert<K>,
+	{
+		self.innermost_mut().insert(key, value)
+	}
+
+	/// Removes a binding from the innermost scope.
+	///
+	/// A binding of the same key in the outer scopes becomes visible again.
+	/// Returns `None` if the innermost scope has no binding for this key,
+	/// even if an outer scope has one.
+	#[inline(always)]
+	pub fn remove<T>(&mut self, key: T) -> Option<M::Item>
+	where
+		S: Collection<Item = M> + BackMut + SimpleCollectionMut,
+		M: Remove<T>,
+	{
+		self.innermost_mut().remove(key)
+	}
+
+	/// Returns the innermost layer.
+	fn innermost_mut(&mut self) -> &mut M
+	where
+		S: Collection<Item = M> + BackMut + SimpleCollectionMut,
+	{
+		// The global scope is never popped, so the stack is never empty.
+		S::into_mut(self.layers.back_mut().expect("no global scope"))
+	}
+
+	/// Returns an iterator over the layers, from the innermost to the outermost.
+	fn scopes(&self) -> impl DoubleEndedIterator<Item = &M>
+	where
+		S: Collection<Item = M> + Len + Get<usize> + SimpleCollectionRef,
+	{
+		(0..self.layers.len())
+			.rev()
+			.filter_map(move |i| self.layers.get(i).map(S::into_ref))
+	}
+
+	/// Returns the index of the innermost layer containing the given key.
+	fn find<T: Copy>(&self, key: T) -> Option<usize>
+	where
+		S: Collection<Item = M> + Len + Get<usize> + SimpleCollectionRef,
+		M: Get<T>,
+	{
+		self.scopes()
+			.position(|layer| layer.contains(key))
+			.map(|i| self.layers.len() - 1 - i)
+	}
+}
+
+impl<S, M: Collection> Collection for ScopedMap<S, M> {
+	type Item = M::Item;
+}
+
+impl<S, M: CollectionRef> CollectionRef for ScopedMap<S, M> {
+	type ItemRef<'a> = M::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_item_ref(r)
+	}
+}
+
+impl<S, M: CollectionMut> CollectionMut for ScopedMap<S, M> {
+	type ItemMut<'a> = M::ItemMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_item_mut(r)
+	}
+}
+
+impl<S, M: Keyed> Keyed for ScopedMap<S, M> {
+	type Key = M::Key;
+}
+
+impl<S, M: KeyedRef> KeyedRef for ScopedMap<S, M> {
+	type KeyRef<'a> = M::KeyRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_key_ref(r)
+	}
+}
+
+impl<S, M> Len for ScopedMap<S, M>
+where
+	S: Collection<Item = M> + Len + Get<usize> + SimpleCollectionRef,
+	M: Len + MapIter + for<'a> Get<&'a <M as Keyed>::Key>,
+{
+	/// Returns the number of visible bindings.
+	///
+	/// This is computed by checking, for each binding,
+	/// that it is not shadowed by an inner scope.
+	/// For `n` bindings in `d` scopes, this performs `O(n * d)` lookups.
+	fn len(&self) -> usize {
+		let mut inner_scopes = 0;
+		self.scopes()
+			.map(|layer| {
+				let visible = layer
+					.iter()
+					.filter(|(key, _)| {
+						!self
+							.scopes()
+							.take(inner_scopes)
+							.any(|inner| inner.contains(&**key))
+					})
+					.count();
+				inner_scopes += 1;
+				visible
+			})
+			.sum()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.scopes().all(|layer| layer.is_empty())
+	}
+}
+
+impl<T: Copy, S, M> Get<T> for ScopedMap<S, M>
+where
+	S: Collection<Item = M> + Len + Get<usize> + SimpleCollectionRef,
+	M: Get<T>,
+{
+	#[inline(always)]
+	fn get(&self, key: T) -> Option<M::ItemRef<'_>> {
+		self.scopes().find_map(|layer| layer.get(key))
+	}
+
+	#[inline(always)]
+	fn contains(&self, key: T) -> bool {
+		self.scopes().any(|layer| layer.contains(key))
+	}
+}
+
+impl<T: Copy, S, M> GetKeyValue<T> for ScopedMap<S, M>
+where
+	S: Collection<Item = M> + Len + Get<usize> + SimpleCollectionRef,
+	M: GetKeyValue<T>,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: T) -> Option<(M::KeyRef<'_>, M::ItemRef<'_>)> {
+		self.scopes().find_map(|layer| layer.get_key_value(key))
+	}
+}
+
+impl<T: Copy, S, M> GetMut<T> for ScopedMap<S, M>
+where
+	S: Collection<Item = M>
+		+ Len
+		+ Get<usize>
+		+ GetMut<usize>
+		+ SimpleCollectionRef
+		+ SimpleCollectionMut,
+	M: Get<T> + GetMut<T>,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: T) -> Option<M::ItemMut<'_>> {
+		let i = self.find(key)?;
+		S::into_mut(self.layers.get_mut(i)?).get_mut(key)
+	}
+}
+
+impl<S, M> Clear for ScopedMap<S, M>
+where
+	S: Collection<Item = M> + Len + GetMut<usize> + SimpleCollectionMut,
+	M: Clear,
+{
+	/// Removes every binding, keeping the scopes open.
+	#[inline(always)]
+	fn clear(&mut self) {
+		for i in 0..self.layers.len() {
+			if let Some(layer) = self.layers.get_mut(i) {
+				S::into_mut(layer).clear()
+			}
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::ScopedMap;
+	use crate::{Clear, Get, GetKeyValue, GetMut, Len};
+	use std::collections::{BTreeMap, VecDeque};
+
+	type Env = ScopedMap<VecDeque<BTreeMap<&'static str, i32>>, BTreeMap<&'static str, i32>>;
+
+	#[test]
+	fn global_scope_is_never_popped() {
+		let mut env = Env::new();
+		assert_eq!(env.pop_scope(), None);
+		assert_eq!(env.pop_scope(), None);
+		assert_eq!(env.depth(), 1);
+
+		// The innermost scope is still available.
+		assert_eq!(env.insert("x", 1), None);
+		assert_eq!(env.remove(&"x"), Some(1));
+	}
+
+	#[test]
+	fn removal_across_scopes() {
+		let mut env = Env::new();
+		env.insert("x", 1);
+		env.push_scope();
+
+		// The binding belongs to the outer scope.
+		assert_eq!(env.remove(&"x"), None);
+		assert_eq!(env.get(&"x"), Some(&1));
+
+		env.insert("x", 2);
+		assert_eq!(env.get_key_value(&"x"), Some((&"x", &2)));
+		assert_eq!(env.remove(&"x"), Some(2));
+		assert_eq!(env.get(&"x"), Some(&1));
+	}
+
+	#[test]
+	fn visible_length() {
+		let mut env = Env::new();
+		env.insert("x", 1);
+		env.insert("y", 2);
+		env.push_scope();
+		env.insert("x", 3);
+		env.push_scope();
+		env.insert("x", 4);
+		env.insert("z", 5);
+
+		assert_eq!(env.len(), 3);
+		assert_eq!(env.pop_scope().map(|layer| layer.len()), Some(2));
+		assert_eq!(env.len(), 2);
+
+		env.clear();
+		assert!(env.is_empty());
+		assert_eq!(env.depth(), 2);
+	}
+
+	#[test]
+	fn mutation_of_the_visible_binding() {
+		let mut env = Env::new();
+		env.insert("x", 1);
+		env.push_scope();
+		env.insert("x", 2);
+
+		*env.get_mut(&"x").unwrap() += 10;
+		assert_eq!(env.pop_scope().unwrap().get(&"x"), Some(&12));
+		assert_eq!(env.get(&"x"), Some(&1));
+		assert_eq!(env.get_mut(&"y"), None);
+	}
+}
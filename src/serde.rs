@@ -0,0 +1,334 @@
+//! Serde helpers to serialize and deserialize any collection implementing the
+//! traits of this crate.
+//!
+//! Each submodule is meant to be used with the `#[serde(with = "...")]`
+//! field attribute:
+//!   - [`seq`] for sequences, using [`Iter`](crate::Iter) and [`PushBack`](crate::PushBack);
+//!   - [`set`] for sets, using [`Iter`](crate::Iter) and [`Insert`](crate::Insert);
+//!   - [`map`] for maps, using [`MapIter`](crate::MapIter) and [`MapInsert`](crate::MapInsert).
+//!
+//! Deserialization starts from an empty collection, created with
+//! [`WithCapacity`](crate::WithCapacity) when the deserializer gives a size hint, or with
+//! [`Default`] otherwise.
+//! Duplicate set items and map keys are detected using the output of the
+//! insertion (see [`InsertOutput`]).
+//!
+//! ## Example
+//!
+//! ```
+//! use serde::{Deserialize, Serialize};
+//! use std::collections::{HashMap, VecDeque};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Config {
+//!     #[serde(with = "cc_traits::serde::seq")]
+//!     hosts: VecDeque<String>,
+//!
+//!     #[serde(with = "cc_traits::serde::map")]
+//!     ports: HashMap<String, u16>,
+//! }
+//!
+//! let config: Config = serde_json::from_str(r#"{
+//!     "hosts": ["a", "b"],
+//!     "ports": { "http": 80, "https": 443 }
+//! }"#).unwrap();
+//! assert_eq!(config.hosts, ["a", "b"]);
+//! assert_eq!(config.ports["https"], 443);
+//!
+//! let error = serde_json::from_str::<Config>(r#"{
+//!     "hosts": [],
+//!     "ports": { "http": 80, "http": 8080 }
+//! }"#);
+//! assert!(error.is_err());
+//! ```
+use crate::build::empty;
+
+/// Maximum initial capacity allocated from a size hint.
+///
+/// Size hints come from the serialized data and cannot be trusted.
+const MAX_CAPACITY_HINT: usize = 4096;
+
+/// Returns the initial capacity allocated for the given size hint.
+fn capacity(size_hint: Option<usize>) -> usize {
+	size_hint.map_or(0, |len| len.min(MAX_CAPACITY_HINT))
+}
+
+/// Output of an insertion ([`Insert`](crate::Insert) or [`MapInsert`](crate::MapInsert)), telling if the
+/// inserted item or key was already present.
+pub trait InsertOutput {
+	/// Checks if the insertion found a duplicate item or key.
+	fn is_duplicate(&self) -> bool;
+}
+
+impl InsertOutput for () {
+	/// Duplicates cannot be detected.
+	#[inline(always)]
+	fn is_duplicate(&self) -> bool {
+		false
+	}
+}
+
+impl InsertOutput for bool {
+	/// Insertion returning `false` when the item was already present.
+	#[inline(always)]
+	fn is_duplicate(&self) -> bool {
+		!*self
+	}
+}
+
+impl<T> InsertOutput for Option<T> {
+	/// Insertion returning the replaced value, if any.
+	#[inline(always)]
+	fn is_duplicate(&self) -> bool {
+		self.is_some()
+	}
+}
+
+/// Sequence (de)serialization, using [`Iter`](crate::Iter) and [`PushBack`](crate::PushBack).
+pub mod seq {
+	use super::{capacity, empty};
+	use crate::{Iter, PushBack, SimpleCollectionRef, WithCapacity};
+	use ::serde::{
+		de::{SeqAccess, Visitor},
+		Deserialize, Deserializer, Serialize, Serializer,
+	};
+	use core::{fmt, marker::PhantomData};
+
+	/// Serializes the given collection as a sequence.
+	pub fn serialize<C, S>(collection: &C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: Iter + SimpleCollectionRef,
+		C::Item: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_seq(collection.iter().map(C::into_ref))
+	}
+
+	/// Deserializes a sequence, pushing each item at the back of the
+	/// collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: Default + WithCapacity + PushBack,
+		C::Item: Deserialize<'de>,
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_seq(SeqVisitor(PhantomData))
+	}
+
+	struct SeqVisitor<C>(PhantomData<C>);
+
+	impl<'de, C> Visitor<'de> for SeqVisitor<C>
+	where
+		C: Default + WithCapacity + PushBack,
+		C::Item: Deserialize<'de>,
+	{
+		type Value = C;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			write!(f, "a sequence")
+		}
+
+		fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+			let mut collection: C = empty(capacity(seq.size_hint()));
+			while let Some(item) = seq.next_element()? {
+				collection.push_back(item);
+			}
+
+			Ok(collection)
+		}
+	}
+}
+
+/// Set (de)serialization, using [`Iter`](crate::Iter) and [`Insert`](crate::Insert).
+///
+/// Deserialization fails on duplicate items.
+pub mod set {
+	use super::{capacity, empty, InsertOutput};
+	use crate::{Insert, Iter, SimpleCollectionRef, WithCapacity};
+	use ::serde::{
+		de::{Error, SeqAccess, Visitor},
+		Deserialize, Deserializer, Serialize, Serializer,
+	};
+	use core::{fmt, marker::PhantomData};
+
+	/// Serializes the given collection as a sequence.
+	pub fn serialize<C, S>(collection: &C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: Iter + SimpleCollectionRef,
+		C::Item: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_seq(collection.iter().map(C::into_ref))
+	}
+
+	/// Deserializes a sequence, inserting each item in the collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: Default + WithCapacity + Insert,
+		C::Item: Deserialize<'de>,
+		C::Output: InsertOutput,
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_seq(SetVisitor(PhantomData))
+	}
+
+	struct SetVisitor<C>(PhantomData<C>);
+
+	impl<'de, C> Visitor<'de> for SetVisitor<C>
+	where
+		C: Default + WithCapacity + Insert,
+		C::Item: Deserialize<'de>,
+		C::Output: InsertOutput,
+	{
+		type Value = C;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			write!(f, "a sequence of unique items")
+		}
+
+		fn visit_seq<A: SeqAccess<'de>>(self, mut seq: A) -> Result<C, A::Error> {
+			let mut collection: C = empty(capacity(seq.size_hint()));
+			while let Some(item) = seq.next_element()? {
+				if collection.insert(item).is_duplicate() {
+					return Err(A::Error::custom("duplicate item"));
+				}
+			}
+
+			Ok(collection)
+		}
+	}
+}
+
+/// Map (de)serialization, using [`MapIter`](crate::MapIter) and [`MapInsert`](crate::MapInsert).
+///
+/// Deserialization fails on duplicate keys.
+pub mod map {
+	use super::{capacity, empty, InsertOutput};
+	use crate::{Keyed, MapInsert, MapIter, SimpleCollectionRef, SimpleKeyedRef, WithCapacity};
+	use ::serde::{
+		de::{Error, MapAccess, Visitor},
+		Deserialize, Deserializer, Serialize, Serializer,
+	};
+	use core::{fmt, marker::PhantomData};
+
+	/// Serializes the given collection as a map.
+	pub fn serialize<C, S>(collection: &C, serializer: S) -> Result<S::Ok, S::Error>
+	where
+		C: MapIter + SimpleKeyedRef + SimpleCollectionRef,
+		C::Key: Serialize,
+		C::Item: Serialize,
+		S: Serializer,
+	{
+		serializer.collect_map(collection.iter().map(|(key, value)| {
+			(
+				<C as SimpleKeyedRef>::into_ref(key),
+				<C as SimpleCollectionRef>::into_ref(value),
+			)
+		}))
+	}
+
+	/// Deserializes a map, inserting each binding in the collection.
+	pub fn deserialize<'de, C, D>(deserializer: D) -> Result<C, D::Error>
+	where
+		C: Default + WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+		C::Key: Deserialize<'de>,
+		C::Item: Deserialize<'de>,
+		C::Output: InsertOutput,
+		D: Deserializer<'de>,
+	{
+		deserializer.deserialize_map(MapVisitor(PhantomData))
+	}
+
+	struct MapVisitor<C>(PhantomData<C>);
+
+	impl<'de, C> Visitor<'de> for MapVisitor<C>
+	where
+		C: Default + WithCapacity + Keyed + MapInsert<<C as Keyed>::Key>,
+		C::Key: Deserialize<'de>,
+		C::Item: Deserialize<'de>,
+		C::Output: InsertOutput,
+	{
+		type Value = C;
+
+		fn expecting(&self, f: &mut fmt::Formatter) -> fmt::Result {
+			write!(f, "a map with unique keys")
+		}
+
+		fn visit_map<A: MapAccess<'de>>(self, mut map: A) -> Result<C, A::Error> {
+			let mut collection: C = empty(capacity(map.size_hint()));
+			while let Some((key, value)) = map.next_entry()? {
+				if collection.insert(key, value).is_duplicate() {
+					return Err(A::Error::custom("duplicate key"));
+				}
+			}
+
+			Ok(collection)
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{capacity, map, seq, set, MAX_CAPACITY_HINT};
+	use ::serde::de::value::{Error, MapDeserializer, SeqDeserializer};
+	use std::collections::{HashMap, HashSet, VecDeque};
+
+	/// Iterator reporting a huge exact size.
+	struct Huge<I>(I);
+
+	impl<I: Iterator> Iterator for Huge<I> {
+		type Item = I::Item;
+
+		fn next(&mut self) -> Option<I::Item> {
+			self.0.next()
+		}
+
+		fn size_hint(&self) -> (usize, Option<usize>) {
+			(usize::MAX, Some(usize::MAX))
+		}
+	}
+
+	#[test]
+	fn capacity_hint_is_capped() {
+		assert_eq!(capacity(None), 0);
+		assert_eq!(capacity(Some(3)), 3);
+		assert_eq!(capacity(Some(usize::MAX)), MAX_CAPACITY_HINT);
+
+		let items = Huge(vec![1u8, 2].into_iter());
+		let result: Result<Vec<u8>, Error> = seq::deserialize(SeqDeserializer::new(items));
+		assert_eq!(result.unwrap(), [1, 2]);
+	}
+
+	#[test]
+	fn duplicate_set_items() {
+		let result: Result<HashSet<i32>, Error> =
+			set::deserialize(SeqDeserializer::new(vec![1, 2].into_iter()));
+		assert_eq!(result.unwrap(), [1, 2].iter().copied().collect());
+
+		let result: Result<HashSet<i32>, Error> =
+			set::deserialize(SeqDeserializer::new(vec![1, 2, 1].into_iter()));
+		assert_eq!(result.unwrap_err().to_string(), "duplicate item");
+	}
+
+	#[test]
+	fn duplicate_map_keys() {
+		let result: Result<HashMap<String, i32>, Error> =
+			map::deserialize(MapDeserializer::new(vec![("a", 1), ("b", 2)].into_iter()));
+		assert_eq!(result.unwrap().len(), 2);
+
+		let result: Result<HashMap<String, i32>, Error> =
+			map::deserialize(MapDeserializer::new(vec![("a", 1), ("a", 2)].into_iter()));
+		assert_eq!(result.unwrap_err().to_string(), "duplicate key");
+	}
+
+	#[cfg(feature = "serde_json")]
+	#[test]
+	fn push_back_round_trip() {
+		let hosts: VecDeque<String> = vec!["a".to_string(), "b".to_string()].into();
+		let json = seq::serialize(&hosts, serde_json::value::Serializer).unwrap();
+		assert_eq!(json, serde_json::json!(["a", "b"]));
+
+		let result: VecDeque<String> = seq::deserialize(json).unwrap();
+		assert_eq!(result, hosts);
+	}
+}
@@ -0,0 +1,378 @@
+//! Sharded map.
+use crate::{
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut, GetMut,
+	Keyed, KeyedRef, Len, MapInsert, MapIter, Remove,
+};
+use core::hash::{BuildHasher, Hash, Hasher};
+use std::collections::hash_map::RandomState;
+
+/// Map distributing its keys across `N` inner maps (or shards).
+///
+/// Each key is hashed using the hash builder `S` to select the
+/// shard storing its binding.
+/// The shards can be accessed individually (for instance with
+/// [`Sharded::shard_mut`] or [`Sharded::into_shards`]), so that each shard
+/// can be put behind its own lock.
+///
+/// Since a key and any of its borrowed forms must have the same hash
+/// (see [`Borrow`](core::borrow::Borrow)), lookups can be performed
+/// with borrowed keys.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, Len, MapInsert, Sharded};
+/// use std::collections::HashMap;
+///
+/// let mut map: Sharded<HashMap<String, i32>, 4> = Sharded::new();
+/// map.insert("a".to_string(), 1);
+/// map.insert("b".to_string(), 2);
+///
+/// assert_eq!(map.len(), 2);
+/// assert_eq!(map.get("a"), Some(&1));
+/// assert_eq!(map.shard("b").get("b"), Some(&2));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Sharded<M, const N: usize, S = RandomState> {
+	shards: [M; N],
+	hasher: S,
+}
+
+impl<M: Default, const N: usize, S: Default> Default for Sharded<M, N, S> {
+	fn default() -> Self {
+		Self::with_hasher(S::default())
+	}
+}
+
+impl<M, const N: usize> Sharded<M, N> {
+	/// Creates a new sharded map with empty shards.
+	///
+	/// `N` must not be zero, which is checked at compile time.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		M: Default,
+	{
+		Self::with_hasher(RandomState::new())
+	}
+}
+
+impl<M, const N: usize, S> Sharded<M, N, S> {
+	/// Compile-time check that there is at least one shard,
+	/// evaluated when [`Sharded::from_shards`] is instantiated.
+	const NON_EMPTY: () = assert!(N > 0, "a sharded map needs at least one shard");
+
+	/// Creates a new sharded map with empty shards,
+	/// using the given hash builder to distribute the keys.
+	///
+	/// `N` must not be zero, which is checked at compile time.
+	#[inline(always)]
+	pub fn with_hasher(hasher: S) -> Self
+	where
+		M: Default,
+	{
+		Self::from_shards(core::array::from_fn(|_| M::default()), hasher)
+	}
+
+	/// Creates a new sharded map from the given shards.
+	///
+	/// The shards must be consistent with the hash builder:
+	/// each key must be stored in the shard given by [`Sharded::shard_index`].
+	///
+	/// `N` must not be zero, which is checked at compile time.
+	///
+	/// ```compile_fail
+	/// use cc_traits::Sharded;
+	/// use std::collections::HashMap;
+	///
+	/// let map: Sharded<HashMap<String, i32>, 0> = Sharded::new();
+	/// ```
+	#[inline(always)]
+	pub fn from_shards(shards: [M; N], hasher: S) -> Self {
+		#[allow(clippy::let_unit_value)]
+		let () = Self::NON_EMPTY;
+		Self { shards, hasher }
+	}
+
+	/// Returns a reference to the hash builder.
+	#[inline(always)]
+	pub fn hasher(&self) -> &S {
+		&self.hasher
+	}
+
+	/// Returns the shards.
+	#[inline(always)]
+	pub fn shards(&self) -> &[M; N] {
+		&self.shards
+	}
+
+	/// Returns the shards, mutably.
+	///
+	/// Moving a key to another shard breaks the lookups of this key.
+	#[inline(always)]
+	pub fn shards_mut(&mut self) -> &mut [M; N] {
+		&mut self.shards
+	}
+
+	/// Consumes the sharded map and returns the shards and the hash builder.
+	#[inline(always)]
+	pub fn into_shards(self) -> ([M; N], S) {
+		(self.shards, self.hasher)
+	}
+
+	/// Returns the index of the shard storing the given key.
+	// `BuildHasher::hash_one` requires Rust 1.71.
+	#[allow(clippy::manual_hash_one)]
+	pub fn shard_index<Q: ?Sized + Hash>(&self, key: &Q) -> usize
+	where
+		S: BuildHasher,
+	{
+		let mut hasher = self.hasher.build_hasher();
+		key.hash(&mut hasher);
+		(hasher.finish() % N as u64) as usize
+	}
+
+	/// Returns the shard storing the given key.
+	#[inline(always)]
+	pub fn shard<Q: ?Sized + Hash>(&self, key: &Q) -> &M
+	where
+		S: BuildHasher,
+	{
+		&self.shards[self.shard_index(key)]
+	}
+
+	/// Returns the shard storing the given key, mutably.
+	#[inline(always)]
+	pub fn shard_mut<Q: ?Sized + Hash>(&mut self, key: &Q) -> &mut M
+	where
+		S: BuildHasher,
+	{
+		let i = self.shard_index(key);
+		&mut self.shards[i]
+	}
+
+	/// Returns an iterator over the key-value pairs of every shard.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, M>
+	where
+		M: MapIter,
+	{
+		Iter {
+			shards: self.shards.iter(),
+			current: None,
+		}
+	}
+}
+
+impl<M: Collection, const N: usize, S> Collection for Sharded<M, N, S> {
+	type Item = M::Item;
+}
+
+impl<M: CollectionRef, const N: usize, S> CollectionRef for Sharded<M, N, S> {
+	type ItemRef<'a> = M::ItemRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_ref<'short, 'long: 'short>(r: Self::ItemRef<'long>) -> Self::ItemRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_item_ref(r)
+	}
+}
+
+impl<M: CollectionMut, const N: usize, S> CollectionMut for Sharded<M, N, S> {
+	type ItemMut<'a> = M::ItemMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_item_mut(r)
+	}
+}
+
+impl<M: Keyed, const N: usize, S> Keyed for Sharded<M, N, S> {
+	type Key = M::Key;
+}
+
+impl<M: KeyedRef, const N: usize, S> KeyedRef for Sharded<M, N, S> {
+	type KeyRef<'a> = M::KeyRef<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_key_ref<'short, 'long: 'short>(r: Self::KeyRef<'long>) -> Self::KeyRef<'short>
+	where
+		Self: 'long,
+	{
+		M::upcast_key_ref(r)
+	}
+}
+
+impl<M: Len, const N: usize, S> Len for Sharded<M, N, S> {
+	/// Returns the total number of bindings in all the shards.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.shards.iter().map(M::len).sum()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.shards.iter().all(M::is_empty)
+	}
+}
+
+impl<'a, Q, M, const N: usize, S> Get<&'a Q> for Sharded<M, N, S>
+where
+	Q: ?Sized + Hash,
+	M: Get<&'a Q>,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<M::ItemRef<'_>> {
+		self.shard(key).get(key)
+	}
+}
+
+impl<'a, Q, M, const N: usize, S> GetMut<&'a Q> for Sharded<M, N, S>
+where
+	Q: ?Sized + Hash,
+	M: GetMut<&'a Q>,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<M::ItemMut<'_>> {
+		self.shard_mut(key).get_mut(key)
+	}
+}
+
+impl<'a, Q, M, const N: usize, S> GetKeyValue<&'a Q> for Sharded<M, N, S>
+where
+	Q: ?Sized + Hash,
+	M: GetKeyValue<&'a Q>,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(M::KeyRef<'_>, M::ItemRef<'_>)> {
+		self.shard(key).get_key_value(key)
+	}
+}
+
+impl<'a, Q, M, const N: usize, S> GetKeyValueMut<&'a Q> for Sharded<M, N, S>
+where
+	Q: ?Sized + Hash,
+	M: GetKeyValueMut<&'a Q>,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, key: &'a Q) -> Option<(M::KeyRef<'_>, M::ItemMut<'_>)> {
+		self.shard_mut(key).get_key_value_mut(key)
+	}
+}
+
+impl<K: Hash, M: MapInsert<K>, const N: usize, S: BuildHasher> MapInsert<K> for Sharded<M, N, S> {
+	type Output = M::Output;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: M::Item) -> M::Output {
+		self.shard_mut(&key).insert(key, value)
+	}
+}
+
+impl<'a, Q, M, const N: usize, S> Remove<&'a Q> for Sharded<M, N, S>
+where
+	Q: ?Sized + Hash,
+	M: Remove<&'a Q>,
+	S: BuildHasher,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a Q) -> Option<M::Item> {
+		self.shard_mut(key).remove(key)
+	}
+}
+
+impl<M: Clear, const N: usize, S> Clear for Sharded<M, N, S> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		for shard in &mut self.shards {
+			shard.clear()
+		}
+	}
+}
+
+impl<M: MapIter, const N: usize, S> MapIter for Sharded<M, N, S> {
+	type Iter<'a> = Iter<'a, M> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+/// Iterator over the key-value pairs of a [`Sharded`] map,
+/// shard after shard.
+pub struct Iter<'a, M: MapIter + 'a> {
+	shards: core::slice::Iter<'a, M>,
+	current: Option<M::Iter<'a>>,
+}
+
+impl<'a, M: MapIter + 'a> Iterator for Iter<'a, M> {
+	type Item = (M::KeyRef<'a>, M::ItemRef<'a>);
+
+	fn next(&mut self) -> Option<Self::Item> {
+		loop {
+			if let Some(item) = self.current.as_mut().and_then(Iterator::next) {
+				return Some(item);
+			}
+
+			self.current = Some(self.shards.next()?.iter());
+		}
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::Sharded;
+	use crate::{Clear, Get, GetMut, Len, MapInsert, MapIter, Remove};
+	use std::{
+		collections::{hash_map::RandomState, BTreeMap},
+		string::String,
+	};
+
+	type Map<const N: usize> = Sharded<BTreeMap<String, u32>, N>;
+
+	#[test]
+	fn keys_are_stored_in_their_shard() {
+		let mut map: Map<4> = Sharded::new();
+		for i in 0..32 {
+			assert_eq!(map.insert(i.to_string(), i), None);
+		}
+
+		assert_eq!(map.len(), 32);
+		assert_eq!(
+			map.shards().iter().map(|shard| shard.len()).sum::<usize>(),
+			32
+		);
+		for (i, shard) in map.shards().iter().enumerate() {
+			assert!(shard.keys().all(|key| map.shard_index(key.as_str()) == i));
+		}
+
+		assert_eq!(MapIter::iter(&map).count(), 32);
+		assert_eq!(map.get("7"), Some(&7));
+		*map.get_mut("7").unwrap() += 1;
+		assert_eq!(map.insert("7".into(), 0), Some(8));
+		assert_eq!(map.remove("7"), Some(0));
+		assert_eq!(map.remove("7"), None);
+		assert_eq!(map.len(), 31);
+
+		map.clear();
+		assert!(map.is_empty());
+	}
+
+	#[test]
+	fn single_shard() {
+		let mut map: Map<1> = Sharded::with_hasher(RandomState::new());
+		map.insert("a".into(), 1);
+		assert_eq!(map.shard_index("anything"), 0);
+		assert_eq!(map.into_shards().0[0].len(), 1);
+	}
+}
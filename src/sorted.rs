@@ -0,0 +1,493 @@
+//! Sorted sequence.
+use crate::{
+	Clear, Collection, CollectionRef, Get, Insert, Len, OrderedRange, PopBack, PushBack, Remove,
+	SimpleCollectionRef,
+};
+use core::{
+	borrow::Borrow,
+	cmp::Ordering,
+	ops::{Bound, Index, IndexMut, RangeBounds},
+};
+
+/// Sorted sequence.
+///
+/// This wraps any vector-like collection `C`
+/// (providing [`Len`] and [`Index<usize>`]),
+/// keeping its items sorted in ascending order.
+/// Items are inserted at their position using a binary search,
+/// which makes [`Sorted`] a [`SetMut<T>`](crate::SetMut) when `C` is a
+/// [`VecMut<T>`](crate::VecMut).
+///
+/// By default, equal items are stored at most once.
+/// A multiset, storing duplicates, can be created with [`Sorted::new_multi`].
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, Insert, OrderedRange, Sorted};
+///
+/// let mut set: Sorted<Vec<i32>> = Sorted::new();
+/// assert!(set.insert(3));
+/// assert!(set.insert(1));
+/// assert!(set.insert(2));
+/// assert!(!set.insert(2));
+///
+/// assert_eq!(set.inner(), &[1, 2, 3]);
+/// assert!(set.contains(&2));
+/// assert_eq!(set.range(2..).copied().collect::<Vec<_>>(), [2, 3]);
+///
+/// let mut multiset: Sorted<Vec<i32>> = Sorted::new_multi();
+/// multiset.insert(2);
+/// multiset.insert(1);
+/// multiset.insert(2);
+/// assert_eq!(multiset.inner(), &[1, 2, 2]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Sorted<C> {
+	inner: C,
+	multi: bool,
+}
+
+impl<C: Default> Default for Sorted<C> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<C> Sorted<C> {
+	/// Creates a new empty sorted sequence storing each item at most once.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		C: Default,
+	{
+		Self {
+			inner: C::default(),
+			multi: false,
+		}
+	}
+
+	/// Creates a new empty sorted sequence that can store duplicate items.
+	#[inline(always)]
+	pub fn new_multi() -> Self
+	where
+		C: Default,
+	{
+		Self {
+			inner: C::default(),
+			multi: true,
+		}
+	}
+
+	/// Checks if the sequence can store duplicate items.
+	#[inline(always)]
+	pub fn is_multi(&self) -> bool {
+		self.multi
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Consumes the wrapper and returns the inner collection.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+
+	/// Returns an iterator over the items of the sequence, in ascending order.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, C>
+	where
+		C: Len,
+	{
+		Iter {
+			inner: &self.inner,
+			start: 0,
+			end: self.inner.len(),
+		}
+	}
+
+	/// Returns the index of the first item that is not ordered before `key`.
+	pub fn lower_bound<Q>(&self, key: &Q) -> usize
+	where
+		C: Len + Index<usize>,
+		C::Output: Borrow<Q>,
+		Q: ?Sized + Ord,
+	{
+		partition_point(&self.inner, |item| item.borrow() < key)
+	}
+
+	/// Returns the index of the first item that is ordered after `key`.
+	pub fn upper_bound<Q>(&self, key: &Q) -> usize
+	where
+		C: Len + Index<usize>,
+		C::Output: Borrow<Q>,
+		Q: ?Sized + Ord,
+	{
+		partition_point(&self.inner, |item| item.borrow() <= key)
+	}
+
+	/// Binary search of the given key.
+	///
+	/// Returns `Ok(index)` with the index of an item equal to `key`,
+	/// or `Err(index)` with the index where `key` would be inserted.
+	pub fn binary_search<Q>(&self, key: &Q) -> Result<usize, usize>
+	where
+		C: Len + Index<usize>,
+		C::Output: Borrow<Q>,
+		Q: ?Sized + Ord,
+	{
+		let i = self.lower_bound(key);
+		if i < self.inner.len() && self.inner[i].borrow().cmp(key) == Ordering::Equal {
+			Ok(i)
+		} else {
+			Err(i)
+		}
+	}
+}
+
+impl<C: Collection> Collection for Sorted<C> {
+	type Item = C::Item;
+}
+
+impl<C: Collection> CollectionRef for Sorted<C> {
+	type ItemRef<'a> = &'a C::Item where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<C: Collection> SimpleCollectionRef for Sorted<C> {
+	crate::simple_collection_ref!();
+}
+
+impl<C: Len> Len for Sorted<C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+}
+
+impl<C: Index<usize>> Index<usize> for Sorted<C> {
+	type Output = C::Output;
+
+	#[inline(always)]
+	fn index(&self, index: usize) -> &C::Output {
+		&self.inner[index]
+	}
+}
+
+impl<C> Get<usize> for Sorted<C>
+where
+	C: Collection + Len + Index<usize, Output = <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn get(&self, index: usize) -> Option<&C::Item> {
+		if index < self.inner.len() {
+			Some(&self.inner[index])
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, Q: ?Sized, C> Get<&'a Q> for Sorted<C>
+where
+	C: Collection + Len + Index<usize, Output = <C as Collection>::Item>,
+	C::Item: Borrow<Q>,
+	Q: Ord,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&C::Item> {
+		self.binary_search(key).ok().map(|i| &self.inner[i])
+	}
+}
+
+impl<C> Insert for Sorted<C>
+where
+	C: Len + PushBack + PopBack + IndexMut<usize, Output = <C as Collection>::Item>,
+	C::Item: Ord,
+{
+	type Output = bool;
+
+	/// Inserts the given item at its position in the sequence.
+	///
+	/// Returns `false` if the item was already present and the sequence
+	/// does not store duplicates, in which case the sequence is unchanged.
+	/// Duplicates are inserted after the equal items.
+	fn insert(&mut self, item: C::Item) -> bool {
+		let index = if self.multi {
+			self.upper_bound(&item)
+		} else {
+			match self.binary_search(&item) {
+				Ok(_) => return false,
+				Err(index) => index,
+			}
+		};
+
+		insert_at(&mut self.inner, index, item);
+		true
+	}
+}
+
+impl<'a, Q: ?Sized, C> Remove<&'a Q> for Sorted<C>
+where
+	C: Len + PushBack + PopBack + IndexMut<usize, Output = <C as Collection>::Item>,
+	C::Item: Borrow<Q>,
+	Q: Ord,
+{
+	/// Removes an item equal to `key`, if any.
+	fn remove(&mut self, key: &'a Q) -> Option<C::Item> {
+		let index = self.binary_search(key).ok()?;
+		remove_at(&mut self.inner, index)
+	}
+}
+
+impl<C> Remove<usize> for Sorted<C>
+where
+	C: Len + PushBack + PopBack + IndexMut<usize, Output = <C as Collection>::Item>,
+{
+	#[inline(always)]
+	fn remove(&mut self, index: usize) -> Option<C::Item> {
+		remove_at(&mut self.inner, index)
+	}
+}
+
+impl<C: PopBack> PopBack for Sorted<C> {
+	#[inline(always)]
+	fn pop_back(&mut self) -> Option<C::Item> {
+		self.inner.pop_back()
+	}
+}
+
+impl<C: Clear> Clear for Sorted<C> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.inner.clear()
+	}
+}
+
+impl<C> crate::Iter for Sorted<C>
+where
+	C: Collection + Len + Index<usize, Output = <C as Collection>::Item>,
+{
+	type Iter<'a> = Iter<'a, C> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<Q: ?Sized, C> OrderedRange<Q> for Sorted<C>
+where
+	C: Collection + Len + Index<usize, Output = <C as Collection>::Item>,
+	C::Item: Borrow<Q>,
+	Q: Ord,
+{
+	type Range<'a> = Iter<'a, C> where Self: 'a;
+
+	fn range<R: RangeBounds<Q>>(&self, range: R) -> Self::Range<'_> {
+		let start = match range.start_bound() {
+			Bound::Included(key) => self.lower_bound(key),
+			Bound::Excluded(key) => self.upper_bound(key),
+			Bound::Unbounded => 0,
+		};
+
+		let end = match range.end_bound() {
+			Bound::Included(key) => self.upper_bound(key),
+			Bound::Excluded(key) => self.lower_bound(key),
+			Bound::Unbounded => self.inner.len(),
+		};
+
+		Iter {
+			inner: &self.inner,
+			start,
+			end: end.max(start),
+		}
+	}
+}
+
+/// Returns the index of the first item of the given sequence for which the
+/// given predicate is false.
+///
+/// The predicate must be true for a prefix of the sequence and false for the rest.
+pub(crate) fn partition_point<C>(sequence: &C, f: impl Fn(&C::Output) -> bool) -> usize
+where
+	C: Len + Index<usize>,
+{
+	let mut start = 0;
+	let mut end = sequence.len();
+
+	while start < end {
+		let mid = start + (end - start) / 2;
+		if f(&sequence[mid]) {
+			start = mid + 1
+		} else {
+			end = mid
+		}
+	}
+
+	start
+}
+
+/// Inserts the given item at the given index of the sequence,
+/// shifting the following items.
+pub(crate) fn insert_at<C>(sequence: &mut C, index: usize, item: C::Item)
+where
+	C: Len + PushBack + IndexMut<usize, Output = <C as Collection>::Item>,
+{
+	let mut item = item;
+	for i in index..sequence.len() {
+		item = core::mem::replace(&mut sequence[i], item);
+	}
+
+	sequence.push_back(item);
+}
+
+/// Removes the item at the given index of the sequence,
+/// shifting the following items.
+pub(crate) fn remove_at<C>(sequence: &mut C, index: usize) -> Option<C::Item>
+where
+	C: Len + PopBack + IndexMut<usize, Output = <C as Collection>::Item>,
+{
+	if index < sequence.len() {
+		let mut item = sequence.pop_back()?;
+		for i in (index..sequence.len()).rev() {
+			item = core::mem::replace(&mut sequence[i], item);
+		}
+
+		Some(item)
+	} else {
+		None
+	}
+}
+
+/// Iterator over the items of a [`Sorted`] sequence.
+pub struct Iter<'a, C> {
+	inner: &'a C,
+	start: usize,
+	end: usize,
+}
+
+impl<'a, C> Clone for Iter<'a, C> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner,
+			start: self.start,
+			end: self.end,
+		}
+	}
+}
+
+impl<'a, C: Index<usize>> Iterator for Iter<'a, C> {
+	type Item = &'a C::Output;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.start;
+		(len, Some(len))
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			let item = &self.inner[self.start];
+			self.start += 1;
+			Some(item)
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, C: Index<usize>> DoubleEndedIterator for Iter<'a, C> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			self.end -= 1;
+			Some(&self.inner[self.end])
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, C: Index<usize>> ExactSizeIterator for Iter<'a, C> {}
+
+#[cfg(test)]
+mod tests {
+	use super::Sorted;
+	use crate::{Get, Insert, OrderedRange, PopBack, Remove};
+	use std::{collections::VecDeque, ops::Bound};
+
+	fn items(set: &Sorted<VecDeque<i32>>) -> Vec<i32> {
+		set.iter().copied().collect()
+	}
+
+	#[test]
+	fn insertion_keeps_order() {
+		let mut set: Sorted<VecDeque<i32>> = Sorted::new();
+		for item in [5, 1, 4, 1, 3, 5, 0].iter().copied() {
+			set.insert(item);
+		}
+
+		assert_eq!(items(&set), [0, 1, 3, 4, 5]);
+		assert_eq!(set.binary_search(&2), Err(2));
+		assert_eq!(set.binary_search(&4), Ok(3));
+		assert_eq!(set.iter().next_back(), Some(&5));
+	}
+
+	#[test]
+	fn multiset_bounds() {
+		let mut set: Sorted<VecDeque<i32>> = Sorted::new_multi();
+		for item in [2, 1, 2, 3, 2].iter().copied() {
+			set.insert(item);
+		}
+
+		assert_eq!(items(&set), [1, 2, 2, 2, 3]);
+		assert_eq!(set.lower_bound(&2), 1);
+		assert_eq!(set.upper_bound(&2), 4);
+		assert_eq!(set.range(2..=2).count(), 3);
+		assert_eq!(set.remove(&2), Some(2));
+		assert_eq!(items(&set), [1, 2, 2, 3]);
+	}
+
+	#[test]
+	fn ranges() {
+		let set: Sorted<Vec<i32>> = {
+			let mut set = Sorted::new();
+			for item in 0..10 {
+				set.insert(item);
+			}
+			set
+		};
+
+		let range = |r: (Bound<i32>, Bound<i32>)| set.range(r).copied().collect::<Vec<_>>();
+		assert_eq!(range((Bound::Excluded(7), Bound::Unbounded)), [8, 9]);
+		assert_eq!(range((Bound::Included(3), Bound::Excluded(5))), [3, 4]);
+		assert!(range((Bound::Excluded(5), Bound::Excluded(3))).is_empty());
+		assert_eq!(set.range(20..).count(), 0);
+	}
+
+	#[test]
+	fn removal() {
+		let mut set: Sorted<Vec<i32>> = Sorted::new();
+		for item in 0..5 {
+			set.insert(item);
+		}
+
+		assert_eq!(Remove::<usize>::remove(&mut set, 1), Some(1));
+		assert_eq!(Remove::<usize>::remove(&mut set, 10), None);
+		assert_eq!(set.remove(&3), Some(3));
+		assert_eq!(set.remove(&3), None);
+		assert_eq!(set.pop_back(), Some(4));
+		assert_eq!(set.inner(), &[0, 2]);
+		assert_eq!(set.get(1), Some(&2));
+		assert!(!set.contains(&1));
+	}
+}
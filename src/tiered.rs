@@ -0,0 +1,619 @@
+//! Two-tier map.
+use alloc::collections::VecDeque;
+use core::cell::RefCell;
+
+use crate::{
+	Capacity, Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetMut, Keyed,
+	KeyedRef, Len, MapInsert, MapIter, MapMut, Remove, SimpleCollectionMut, SimpleCollectionRef,
+	SimpleKeyedRef,
+};
+
+/// Map able to choose an entry to evict, such as an [`Lru`](crate::Lru) cache.
+pub trait Evict: Keyed {
+	/// Removes and returns the entry that should be evicted first, if any.
+	fn evict(&mut self) -> Option<(Self::Key, Self::Item)>;
+}
+
+/// Write policy of a [`Tiered`] map, with front map `F` and back map `S`.
+pub trait WritePolicy<K, V, F, S> {
+	/// Returns the number of distinct keys stored in the tiers.
+	fn len(&self, front: &F, back: &S) -> usize;
+
+	/// Inserts a new binding, returning the previous value of the key.
+	fn insert(&self, front: &mut F, back: &mut S, key: K, value: V) -> Option<V>;
+
+	/// Promotes the binding of the given key to the front map, if it is
+	/// only stored in the back map.
+	fn promote(&self, front: &mut F, back: &mut S, key: &K);
+
+	/// Handles a binding evicted from the front map.
+	fn evicted(&self, back: &mut S, key: K, value: V);
+
+	/// Returns a mutable reference to the value of the given key.
+	fn get_mut<'a>(&self, front: &'a mut F, back: &'a mut S, key: &K) -> Option<&'a mut V>;
+
+	/// Removes the binding of the given key from both tiers,
+	/// returning its value.
+	fn remove(&self, front: &mut F, back: &mut S, key: &K) -> Option<V>;
+
+	/// Writes every pending binding of the front map to the back map,
+	/// keeping it in the front map.
+	fn flush(&self, front: &mut F, back: &mut S);
+}
+
+/// Write policy writing every binding to both tiers.
+///
+/// The front map is a cache of the back map: promotion copies the
+/// value (which must be [`Clone`]) and eviction simply drops it.
+/// Mutable accesses ([`GetMut`]) are performed on the back map,
+/// after the key is removed from the front map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WriteThrough;
+
+/// Write policy writing bindings to the back map only when they are
+/// evicted from the front map.
+///
+/// The front map holds the latest value of its bindings: insertions and
+/// mutable accesses ([`GetMut`]) are performed on the front map, leaving the
+/// back map out of date until the binding is evicted. Promotion copies the
+/// value (which must be [`Clone`]) to the front map.
+/// Use [`Tiered::flush`] to write all the bindings to the back map without
+/// evicting them.
+///
+/// Since a key may be stored in both tiers, [`Len`] iterates over the front
+/// map ([`MapIter`]) to count the keys that are not yet in the back map.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct WriteBack;
+
+/// Inserts a binding in the front map, evicting a binding first if
+/// the front map is full.
+fn cache<K, V, F, S, P>(policy: &P, front: &mut F, back: &mut S, key: K, value: V) -> Option<V>
+where
+	F: MapMut<K, V> + Capacity + Evict,
+	P: WritePolicy<K, V, F, S>,
+{
+	if front.capacity() == 0 {
+		policy.evicted(back, key, value);
+		return None;
+	}
+
+	if !front.contains(&key) && front.len() >= front.capacity() {
+		if let Some((k, v)) = front.evict() {
+			policy.evicted(back, k, v)
+		}
+	}
+
+	front.insert(key, value)
+}
+
+impl<K, V, F, S> WritePolicy<K, V, F, S> for WriteThrough
+where
+	K: Clone,
+	V: Clone,
+	F: MapMut<K, V> + Capacity + Evict,
+	S: MapMut<K, V> + SimpleCollectionRef + SimpleCollectionMut,
+{
+	#[inline(always)]
+	fn len(&self, _front: &F, back: &S) -> usize {
+		back.len()
+	}
+
+	fn insert(&self, front: &mut F, back: &mut S, key: K, value: V) -> Option<V> {
+		cache(self, front, back, key.clone(), value.clone());
+		back.insert(key, value)
+	}
+
+	fn promote(&self, front: &mut F, back: &mut S, key: &K) {
+		if !front.contains(key) {
+			if let Some(value) = back.get(key).map(|v| S::into_ref(v).clone()) {
+				cache(self, front, back, key.clone(), value);
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn evicted(&self, _back: &mut S, _key: K, _value: V) {}
+
+	fn get_mut<'a>(&self, front: &'a mut F, back: &'a mut S, key: &K) -> Option<&'a mut V> {
+		front.remove(key);
+		back.get_mut(key).map(S::into_mut)
+	}
+
+	fn remove(&self, front: &mut F, back: &mut S, key: &K) -> Option<V> {
+		front.remove(key);
+		back.remove(key)
+	}
+
+	#[inline(always)]
+	fn flush(&self, _front: &mut F, _back: &mut S) {}
+}
+
+impl<K, V, F, S> WritePolicy<K, V, F, S> for WriteBack
+where
+	K: Clone,
+	V: Clone,
+	F: MapMut<K, V>
+		+ Capacity
+		+ Evict
+		+ MapIter
+		+ SimpleCollectionRef
+		+ SimpleCollectionMut
+		+ SimpleKeyedRef,
+	S: MapMut<K, V> + SimpleCollectionRef + SimpleCollectionMut,
+{
+	/// Counts the bindings of the back map, and the bindings of the front
+	/// map that are not yet written to the back map.
+	///
+	/// This iterates over the front map.
+	fn len(&self, front: &F, back: &S) -> usize {
+		let pending = front
+			.iter()
+			.map(|(key, _)| <F as SimpleKeyedRef>::into_ref(key))
+			.filter(|key| !back.contains(*key))
+			.count();
+		back.len() + pending
+	}
+
+	fn insert(&self, front: &mut F, back: &mut S, key: K, value: V) -> Option<V> {
+		let old = if front.contains(&key) {
+			None
+		} else {
+			back.remove(&key)
+		};
+
+		cache(self, front, back, key, value).or(old)
+	}
+
+	fn promote(&self, front: &mut F, back: &mut S, key: &K) {
+		if front.capacity() > 0 && !front.contains(key) {
+			if let Some(value) = back.get(key).map(|v| S::into_ref(v).clone()) {
+				cache(self, front, back, key.clone(), value);
+			}
+		}
+	}
+
+	#[inline(always)]
+	fn evicted(&self, back: &mut S, key: K, value: V) {
+		back.insert(key, value);
+	}
+
+	fn get_mut<'a>(&self, front: &'a mut F, back: &'a mut S, key: &K) -> Option<&'a mut V> {
+		WritePolicy::<K, V, F, S>::promote(self, front, back, key);
+
+		if front.contains(key) {
+			return front.get_mut(key).map(F::into_mut);
+		}
+
+		back.get_mut(key).map(S::into_mut)
+	}
+
+	fn remove(&self, front: &mut F, back: &mut S, key: &K) -> Option<V> {
+		let value = front.remove(key);
+		let written = back.remove(key);
+		value.or(written)
+	}
+
+	fn flush(&self, front: &mut F, back: &mut S) {
+		for (key, value) in front.iter() {
+			back.insert(
+				<F as SimpleKeyedRef>::into_ref(key).clone(),
+				<F as SimpleCollectionRef>::into_ref(value).clone(),
+			);
+		}
+	}
+}
+
+/// Two-tier map, combining a small and fast front map `F` with a large
+/// backing map `S`.
+///
+/// Lookups check the front map, then the back map, and promote the bindings
+/// found in the back map to the front map.
+/// Mutable lookups ([`GetMut`]) and [`Tiered::fetch`] promote immediately.
+/// Immutable lookups ([`Get`] and [`GetKeyValue`], hence the
+/// [`Map`](crate::Map) implementation) cannot modify the tiers while the
+/// returned reference is alive: like the recency of an [`Lru`](crate::Lru)
+/// cache, their hits are recorded through interior mutability, and the
+/// recorded bindings are promoted before the next mutable operation.
+/// At most [`Capacity::capacity`] hits of the front map are recorded.
+/// When the front map is full (according to its [`Capacity`]),
+/// it chooses a binding to [`Evict`] before receiving a new one.
+/// How bindings are written to the back map is decided by the write policy
+/// `P`: [`WriteThrough`] (the default) or [`WriteBack`].
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{tiered::WriteBack, Get, Len, Lru, MapInsert, Tiered};
+/// use std::collections::{BTreeMap, HashMap};
+///
+/// let mut map = Tiered::with_policy(
+///   Lru::<HashMap<&str, usize>, &str, i32>::new(1),
+///   BTreeMap::new(),
+///   WriteBack,
+/// );
+/// map.insert("a", 1);
+/// map.insert("b", 2); // evicts `a` to the back map.
+/// assert_eq!(map.back().get(&"a"), Some(&1));
+///
+/// assert_eq!(map.fetch(&"a"), Some(&1)); // promotes `a`.
+/// assert_eq!(map.front().get(&"a"), Some(&1));
+/// assert_eq!(map.len(), 2);
+///
+/// map.flush(); // writes `a` back, keeping it in the front map.
+/// assert_eq!(map.back().len(), 2);
+/// assert_eq!(map.front().get(&"a"), Some(&1));
+/// ```
+#[derive(Clone, Debug)]
+pub struct Tiered<F: Keyed, S, P = WriteThrough> {
+	front: F,
+	back: S,
+	policy: P,
+
+	/// Keys found in the back map by immutable lookups, to be promoted.
+	hits: RefCell<VecDeque<F::Key>>,
+}
+
+impl<F: Keyed, S> Tiered<F, S> {
+	/// Creates a new write-through two-tier map.
+	#[inline(always)]
+	pub fn new(front: F, back: S) -> Self {
+		Self::with_policy(front, back, WriteThrough)
+	}
+}
+
+impl<F: Keyed, S, P> Tiered<F, S, P> {
+	/// Creates a new two-tier map with the given write policy.
+	#[inline(always)]
+	pub fn with_policy(front: F, back: S, policy: P) -> Self {
+		Self {
+			front,
+			back,
+			policy,
+			hits: RefCell::new(VecDeque::new()),
+		}
+	}
+
+	/// Returns a reference to the front map.
+	#[inline(always)]
+	pub fn front(&self) -> &F {
+		&self.front
+	}
+
+	/// Returns a reference to the back map.
+	#[inline(always)]
+	pub fn back(&self) -> &S {
+		&self.back
+	}
+
+	/// Returns a reference to the write policy.
+	#[inline(always)]
+	pub fn policy(&self) -> &P {
+		&self.policy
+	}
+
+	/// Consumes the two-tier map and returns the front and back maps.
+	///
+	/// With the [`WriteBack`] policy, the bindings that are only stored in the
+	/// front map are not written to the back map. Call [`Tiered::flush`] first
+	/// to write them.
+	#[inline(always)]
+	pub fn into_parts(self) -> (F, S) {
+		(self.front, self.back)
+	}
+
+	/// Returns a reference to the value of the given key,
+	/// promoting it to the front map if necessary.
+	pub fn fetch<K, V>(&mut self, key: &K) -> Option<&V>
+	where
+		F: Keyed<Key = K, Item = V> + for<'a> Get<&'a K> + SimpleCollectionRef,
+		S: Keyed<Key = K, Item = V> + for<'a> Get<&'a K> + SimpleCollectionRef,
+		P: WritePolicy<K, V, F, S>,
+	{
+		self.promote_hits();
+		self.policy.promote(&mut self.front, &mut self.back, key);
+		match self.front.get(key) {
+			Some(value) => Some(F::into_ref(value)),
+			None => self.back.get(key).map(S::into_ref),
+		}
+	}
+
+	/// Writes every pending binding of the front map to the back map.
+	///
+	/// The bindings stay in the front map.
+	#[inline(always)]
+	pub fn flush<K, V>(&mut self)
+	where
+		F: Keyed<Key = K, Item = V>,
+		P: WritePolicy<K, V, F, S>,
+	{
+		self.promote_hits();
+		self.policy.flush(&mut self.front, &mut self.back)
+	}
+
+	/// Records a hit of the back map, to be promoted by the next mutable
+	/// operation.
+	fn record_hit(&self, key: &F::Key)
+	where
+		F: Capacity,
+		F::Key: Clone,
+	{
+		let capacity = self.front.capacity();
+		if capacity > 0 {
+			let mut hits = self.hits.borrow_mut();
+			if hits.len() >= capacity {
+				hits.pop_front();
+			}
+
+			hits.push_back(key.clone())
+		}
+	}
+
+	/// Promotes the bindings recorded by immutable lookups.
+	fn promote_hits<K, V>(&mut self)
+	where
+		F: Keyed<Key = K, Item = V>,
+		P: WritePolicy<K, V, F, S>,
+	{
+		for key in self.hits.get_mut().drain(..) {
+			self.policy.promote(&mut self.front, &mut self.back, &key)
+		}
+	}
+}
+
+impl<F: Keyed, S, P> Collection for Tiered<F, S, P> {
+	type Item = F::Item;
+}
+
+impl<F: Keyed, S, P> CollectionRef for Tiered<F, S, P> {
+	type ItemRef<'a> = &'a F::Item where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<F: Keyed, S, P> SimpleCollectionRef for Tiered<F, S, P> {
+	crate::simple_collection_ref!();
+}
+
+impl<F: Keyed, S, P> CollectionMut for Tiered<F, S, P> {
+	type ItemMut<'a> = &'a mut F::Item where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<F: Keyed, S, P> SimpleCollectionMut for Tiered<F, S, P> {
+	crate::simple_collection_mut!();
+}
+
+impl<F: Keyed, S, P> Keyed for Tiered<F, S, P> {
+	type Key = F::Key;
+}
+
+impl<F: Keyed, S, P> KeyedRef for Tiered<F, S, P> {
+	type KeyRef<'a> = &'a F::Key where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<F: Keyed, S, P> SimpleKeyedRef for Tiered<F, S, P> {
+	crate::simple_keyed_ref!();
+}
+
+impl<K, V, F, S, P> Len for Tiered<F, S, P>
+where
+	F: Keyed<Key = K, Item = V>,
+	P: WritePolicy<K, V, F, S>,
+{
+	/// Returns the number of distinct keys stored in the tiers.
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.policy.len(&self.front, &self.back)
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.len() == 0
+	}
+}
+
+impl<'a, Q: ?Sized, F, S, P> Get<&'a Q> for Tiered<F, S, P>
+where
+	F: Keyed + Capacity + Get<&'a Q> + SimpleCollectionRef,
+	F::Key: Clone,
+	S: GetKeyValue<&'a Q>
+		+ SimpleCollectionRef
+		+ SimpleKeyedRef
+		+ Keyed<Key = F::Key, Item = F::Item>,
+{
+	/// Returns the value of the given key.
+	///
+	/// A binding found in the back map is promoted by the next mutable
+	/// operation.
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&F::Item> {
+		match self.front.get(key) {
+			Some(value) => Some(F::into_ref(value)),
+			None => self.back.get_key_value(key).map(|(k, v)| {
+				let k = <S as SimpleKeyedRef>::into_ref(k);
+				self.record_hit(k);
+				<S as SimpleCollectionRef>::into_ref(v)
+			}),
+		}
+	}
+}
+
+impl<'a, Q: ?Sized, F, S, P> GetKeyValue<&'a Q> for Tiered<F, S, P>
+where
+	F: Capacity + GetKeyValue<&'a Q> + SimpleCollectionRef + SimpleKeyedRef,
+	F::Key: Clone,
+	S: GetKeyValue<&'a Q>
+		+ SimpleCollectionRef
+		+ SimpleKeyedRef
+		+ Keyed<Key = F::Key, Item = F::Item>,
+{
+	/// Returns the binding of the given key.
+	///
+	/// A binding found in the back map is promoted by the next mutable
+	/// operation.
+	fn get_key_value(&self, key: &'a Q) -> Option<(&F::Key, &F::Item)> {
+		match self.front.get_key_value(key) {
+			Some((k, v)) => Some((
+				<F as SimpleKeyedRef>::into_ref(k),
+				<F as SimpleCollectionRef>::into_ref(v),
+			)),
+			None => self.back.get_key_value(key).map(|(k, v)| {
+				let k = <S as SimpleKeyedRef>::into_ref(k);
+				self.record_hit(k);
+				(k, <S as SimpleCollectionRef>::into_ref(v))
+			}),
+		}
+	}
+}
+
+impl<'a, K, V, F, S, P> GetMut<&'a K> for Tiered<F, S, P>
+where
+	F: Keyed<Key = K, Item = V> + Capacity + Get<&'a K> + SimpleCollectionRef,
+	K: Clone,
+	S: Keyed<Key = K, Item = V> + GetKeyValue<&'a K> + SimpleCollectionRef + SimpleKeyedRef,
+	P: WritePolicy<K, V, F, S>,
+{
+	/// Returns a mutable reference to the value of the given key,
+	/// according to the write policy.
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a K) -> Option<&mut V> {
+		self.promote_hits();
+		self.policy.get_mut(&mut self.front, &mut self.back, key)
+	}
+}
+
+impl<K, V, F, S, P> MapInsert<K> for Tiered<F, S, P>
+where
+	F: Keyed<Key = K, Item = V>,
+	P: WritePolicy<K, V, F, S>,
+{
+	type Output = Option<V>;
+
+	#[inline(always)]
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		self.promote_hits();
+		self.policy
+			.insert(&mut self.front, &mut self.back, key, value)
+	}
+}
+
+impl<'a, K, V, F, S, P> Remove<&'a K> for Tiered<F, S, P>
+where
+	F: Keyed<Key = K, Item = V>,
+	P: WritePolicy<K, V, F, S>,
+{
+	#[inline(always)]
+	fn remove(&mut self, key: &'a K) -> Option<V> {
+		self.promote_hits();
+		self.policy.remove(&mut self.front, &mut self.back, key)
+	}
+}
+
+impl<F: Keyed + Clear, S: Clear, P> Clear for Tiered<F, S, P> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.hits.get_mut().clear();
+		self.front.clear();
+		self.back.clear()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{Tiered, WriteBack};
+	use crate::{Get, GetMut, Len, Lru, MapInsert, Remove};
+	use std::collections::{BTreeMap, HashMap};
+
+	type Front = Lru<HashMap<&'static str, usize>, &'static str, i32>;
+
+	#[test]
+	fn write_through_writes_both_tiers() {
+		let mut map = Tiered::new(Front::new(1), BTreeMap::new());
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert_eq!(map.front().get(&"a"), None);
+		assert_eq!(map.back().get(&"a"), Some(&1));
+		assert_eq!(map.len(), 2);
+
+		assert_eq!(map.fetch(&"a"), Some(&1));
+		assert_eq!(map.front().get(&"a"), Some(&1));
+
+		*map.get_mut(&"a").unwrap() = 10;
+		assert_eq!(map.get(&"a"), Some(&10));
+		assert_eq!(map.remove(&"a"), Some(10));
+		assert_eq!(map.get(&"a"), None);
+		assert_eq!(map.len(), 1);
+	}
+
+	#[test]
+	fn get_promotes_before_the_next_mutation() {
+		let mut map = Tiered::with_policy(Front::new(1), BTreeMap::new(), WriteBack);
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert_eq!(map.get(&"a"), Some(&1));
+		assert_eq!(map.front().get(&"a"), None);
+
+		map.insert("c", 3);
+		assert_eq!(map.front().get(&"c"), Some(&3));
+		assert_eq!(map.back().get(&"a"), Some(&1));
+		assert_eq!(map.back().get(&"b"), Some(&2));
+		assert_eq!(map.len(), 3);
+
+		let mut map = Tiered::new(Front::new(1), BTreeMap::new());
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert_eq!(map.get(&"a"), Some(&1));
+		assert_eq!(map.remove(&"b"), Some(2));
+		assert_eq!(map.front().get(&"a"), Some(&1));
+	}
+
+	#[test]
+	fn fetch_promotes() {
+		let mut map = Tiered::with_policy(Front::new(1), BTreeMap::new(), WriteBack);
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert_eq!(map.fetch(&"a"), Some(&1));
+		assert_eq!(map.front().get(&"a"), Some(&1));
+		assert_eq!(map.back().get(&"b"), Some(&2));
+	}
+
+	#[test]
+	fn write_back_flush_keeps_the_front() {
+		let mut map = Tiered::with_policy(Front::new(2), BTreeMap::new(), WriteBack);
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert!(map.back().is_empty());
+
+		map.flush();
+		assert_eq!(map.back().get(&"a"), Some(&1));
+		assert_eq!(map.front().len(), 2);
+		assert_eq!(map.len(), 2);
+
+		*map.get_mut(&"a").unwrap() = 10;
+		assert_eq!(map.back().get(&"a"), Some(&1));
+		assert_eq!(map.get(&"a"), Some(&10));
+
+		map.insert("c", 3); // evicts `b`.
+		map.insert("d", 4); // evicts `a`, writing it back.
+		assert_eq!(map.back().get(&"a"), Some(&10));
+		assert_eq!(map.len(), 4);
+
+		assert_eq!(map.remove(&"a"), Some(10));
+		assert_eq!(map.remove(&"d"), Some(4));
+		assert_eq!(map.get(&"a"), None);
+		assert_eq!(map.len(), 2);
+	}
+
+	#[test]
+	fn write_back_insert_returns_the_written_value() {
+		let mut map = Tiered::with_policy(Front::new(1), BTreeMap::new(), WriteBack);
+		map.insert("a", 1);
+		map.insert("b", 2);
+		assert_eq!(map.insert("a", 3), Some(1));
+		assert_eq!(map.back().get(&"a"), None);
+		assert_eq!(map.len(), 2);
+	}
+}
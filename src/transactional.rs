@@ -0,0 +1,647 @@
+use crate::{
+	Collection, CollectionMut, Get, GetMut, Insert, Len, MapInsert, PopBack, PushBack, Remove, Sort,
+};
+use alloc::vec::Vec;
+use core::{
+	cmp::Ordering,
+	ops::{Index, IndexMut},
+};
+
+/// Entry of the undo log of a [`Transactional`] collection.
+pub trait Undo<C> {
+	/// Reverts the operation that produced this entry.
+	fn undo(self, collection: &mut C);
+}
+
+/// Undo log entry for maps.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MapUndo<K, V> {
+	/// Remove the given key (reverts an insertion).
+	Remove(K),
+
+	/// Insert back the given key-value pair (reverts a removal or replacement).
+	Insert(K, V),
+}
+
+impl<K, V, C> Undo<C> for MapUndo<K, V>
+where
+	C: Collection<Item = V> + MapInsert<K> + for<'a> Remove<&'a K>,
+{
+	#[inline(always)]
+	fn undo(self, collection: &mut C) {
+		match self {
+			Self::Remove(key) => {
+				collection.remove(&key);
+			}
+			Self::Insert(key, value) => {
+				collection.insert(key, value);
+			}
+		}
+	}
+}
+
+/// Undo log entry for sets.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum SetUndo<T> {
+	/// Remove the given item (reverts an insertion).
+	Remove(T),
+
+	/// Insert back the given item (reverts a removal).
+	Insert(T),
+}
+
+impl<T, C> Undo<C> for SetUndo<T>
+where
+	C: Insert<Item = T> + for<'a> Remove<&'a T>,
+{
+	#[inline(always)]
+	fn undo(self, collection: &mut C) {
+		match self {
+			Self::Remove(item) => {
+				collection.remove(&item);
+			}
+			Self::Insert(item) => {
+				collection.insert(item);
+			}
+		}
+	}
+}
+
+/// Undo log entry for vectors.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum VecUndo<T> {
+	/// Pop the back item (reverts a push).
+	PopBack,
+
+	/// Push back the given item (reverts a pop).
+	PushBack(T),
+
+	/// Put back the given item at the given index (reverts a mutable access).
+	Set(usize, T),
+}
+
+impl<T, C> Undo<C> for VecUndo<T>
+where
+	C: Collection<Item = T> + PushBack + PopBack + GetMut<usize>,
+{
+	#[inline(always)]
+	fn undo(self, collection: &mut C) {
+		match self {
+			Self::PopBack => {
+				collection.pop_back();
+			}
+			Self::PushBack(item) => {
+				collection.push_back(item);
+			}
+			Self::Set(index, item) => {
+				if let Some(mut slot) = collection.get_mut(index) {
+					*slot = item
+				}
+			}
+		}
+	}
+}
+
+/// Collection with commit/rollback support.
+///
+/// Every modification made through the [`MapMut`](crate::MapMut),
+/// [`SetMut`](crate::SetMut) or [`VecMut`](crate::VecMut) traits
+/// while a transaction is open is recorded in an undo log of `U` entries
+/// ([`MapUndo`], [`SetUndo`] or [`VecUndo`]),
+/// so that it can be reverted with [`rollback`](Transactional::rollback).
+/// Transactions can be nested: each call to [`begin`](Transactional::begin)
+/// opens a new savepoint that is committed or rolled back independently.
+///
+/// The old state of the collection is captured using the values returned by
+/// [`MapInsert`] and [`Remove`].
+/// Since those values must also be returned to the caller, items are required to be [`Clone`].
+/// Mutable references given by [`GetMut`] or [`IndexMut`] also clone the
+/// referenced item beforehand so that its current value can be restored.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, MapInsert, MapUndo, Transactional};
+/// use std::collections::HashMap;
+///
+/// let mut map: Transactional<HashMap<&str, i32>, MapUndo<&str, i32>> = Transactional::new(HashMap::new());
+/// map.insert("a", 1);
+///
+/// map.begin();
+/// map.insert("a", 2);
+/// map.insert("b", 3);
+///
+/// map.begin();
+/// map.insert("c", 4);
+/// map.commit();
+///
+/// assert_eq!(map.get(&"c"), Some(&4));
+/// map.rollback();
+///
+/// assert_eq!(map.get(&"a"), Some(&1));
+/// assert_eq!(map.get(&"b"), None);
+/// assert_eq!(map.get(&"c"), None);
+/// ```
+///
+/// The wrapper preserves the [`MapMut`](crate::MapMut),
+/// [`SetMut`](crate::SetMut) and [`VecMut`](crate::VecMut) interfaces:
+///
+/// ```
+/// use cc_traits::{SetMut, SetUndo, Transactional, VecMut, VecUndo};
+/// use std::collections::HashSet;
+///
+/// fn fill<S: SetMut<i32>, V: VecMut<i32>>(set: &mut S, vec: &mut V) {
+///   set.insert(1);
+///   vec.push_back(1);
+///   vec[0] = 2;
+/// }
+///
+/// let mut set: Transactional<HashSet<i32>, SetUndo<i32>> = Transactional::default();
+/// let mut vec: Transactional<Vec<i32>, VecUndo<i32>> = Transactional::new(vec![0]);
+///
+/// set.begin();
+/// vec.begin();
+/// fill(&mut set, &mut vec);
+/// assert_eq!(vec.inner(), &[2, 1]);
+///
+/// set.rollback();
+/// vec.rollback();
+/// assert!(set.inner().is_empty());
+/// assert_eq!(vec.inner(), &[0]);
+/// ```
+#[derive(Clone, Debug)]
+pub struct Transactional<C, U> {
+	inner: C,
+	log: Vec<U>,
+	savepoints: Vec<usize>,
+}
+
+impl<C: Default, U> Default for Transactional<C, U> {
+	fn default() -> Self {
+		Self::new(C::default())
+	}
+}
+
+impl<C, U> Transactional<C, U> {
+	/// Wraps the given collection.
+	///
+	/// No transaction is open at first.
+	#[inline(always)]
+	pub fn new(inner: C) -> Self {
+		Self {
+			inner,
+			log: Vec::new(),
+			savepoints: Vec::new(),
+		}
+	}
+
+	/// Returns a reference to the inner collection.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Returns the number of currently open (nested) transactions.
+	#[inline(always)]
+	pub fn depth(&self) -> usize {
+		self.savepoints.len()
+	}
+
+	/// Checks if a transaction is currently open.
+	#[inline(always)]
+	pub fn in_transaction(&self) -> bool {
+		!self.savepoints.is_empty()
+	}
+
+	/// Opens a new transaction.
+	///
+	/// If a transaction is already open, this creates a nested savepoint.
+	#[inline(always)]
+	pub fn begin(&mut self) {
+		self.savepoints.push(self.log.len())
+	}
+
+	/// Commits the innermost transaction.
+	///
+	/// Changes committed by a nested transaction are still
+	/// reverted if the enclosing transaction is rolled back.
+	///
+	/// Returns `false` if no transaction was open.
+	pub fn commit(&mut self) -> bool {
+		match self.savepoints.pop() {
+			Some(_) => {
+				if self.savepoints.is_empty() {
+					self.log.clear()
+				}
+
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Reverts every modification made since the innermost
+	/// transaction was opened, and closes it.
+	///
+	/// Returns `false` if no transaction was open.
+	pub fn rollback(&mut self) -> bool
+	where
+		U: Undo<C>,
+	{
+		match self.savepoints.pop() {
+			Some(savepoint) => {
+				while self.log.len() > savepoint {
+					self.log.pop().unwrap().undo(&mut self.inner)
+				}
+
+				true
+			}
+			None => false,
+		}
+	}
+
+	/// Consumes the wrapper and returns the inner collection.
+	///
+	/// Open transactions are implicitly committed.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+
+	/// Records the given undo log entry, if a transaction is open.
+	#[inline(always)]
+	fn record(&mut self, f: impl FnOnce() -> U) {
+		if self.in_transaction() {
+			self.log.push(f())
+		}
+	}
+}
+
+crate::macros::forward_read!(Transactional<C, U>.inner);
+
+impl<C: CollectionMut, U> CollectionMut for Transactional<C, U> {
+	type ItemMut<'a> = C::ItemMut<'a> where Self: 'a;
+
+	#[inline(always)]
+	fn upcast_item_mut<'short, 'long: 'short>(r: Self::ItemMut<'long>) -> Self::ItemMut<'short>
+	where
+		Self: 'long,
+	{
+		C::upcast_item_mut(r)
+	}
+}
+
+impl<K, V, C> MapInsert<K> for Transactional<C, MapUndo<K, V>>
+where
+	K: Clone,
+	V: Clone,
+	C: Collection<Item = V> + MapInsert<K, Output = Option<V>>,
+{
+	type Output = Option<V>;
+
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		let logged_key = self.in_transaction().then(|| key.clone());
+		let old = self.inner.insert(key, value);
+
+		if let Some(key) = logged_key {
+			self.log.push(match &old {
+				Some(old) => MapUndo::Insert(key, old.clone()),
+				None => MapUndo::Remove(key),
+			})
+		}
+
+		old
+	}
+}
+
+impl<'a, K, V, C> Remove<&'a K> for Transactional<C, MapUndo<K, V>>
+where
+	K: Clone,
+	V: Clone,
+	C: Collection<Item = V> + Remove<&'a K>,
+{
+	fn remove(&mut self, key: &'a K) -> Option<V> {
+		let old = self.inner.remove(key);
+
+		if let Some(old) = &old {
+			self.record(|| MapUndo::Insert(key.clone(), old.clone()))
+		}
+
+		old
+	}
+}
+
+impl<'a, K, V, C> GetMut<&'a K> for Transactional<C, MapUndo<K, V>>
+where
+	K: Clone,
+	V: Clone,
+	C: Collection<Item = V> + GetMut<&'a K>,
+{
+	fn get_mut(&mut self, key: &'a K) -> Option<C::ItemMut<'_>> {
+		if self.in_transaction() {
+			if let Some(value) = self.inner.get(key) {
+				let old = (*value).clone();
+				self.log.push(MapUndo::Insert(key.clone(), old))
+			}
+		}
+
+		self.inner.get_mut(key)
+	}
+}
+
+impl<T, C> Insert for Transactional<C, SetUndo<T>>
+where
+	T: Clone,
+	C: Insert<Item = T, Output = bool>,
+{
+	type Output = bool;
+
+	fn insert(&mut self, item: T) -> bool {
+		let logged_item = self.in_transaction().then(|| item.clone());
+		let inserted = self.inner.insert(item);
+
+		if inserted {
+			if let Some(item) = logged_item {
+				self.log.push(SetUndo::Remove(item))
+			}
+		}
+
+		inserted
+	}
+}
+
+impl<'a, T, C> Remove<&'a T> for Transactional<C, SetUndo<T>>
+where
+	T: Clone,
+	C: Collection<Item = T> + Remove<&'a T>,
+{
+	fn remove(&mut self, item: &'a T) -> Option<T> {
+		let old = self.inner.remove(item);
+
+		if let Some(old) = &old {
+			self.record(|| SetUndo::Insert(old.clone()))
+		}
+
+		old
+	}
+}
+
+impl<T, C> PushBack for Transactional<C, VecUndo<T>>
+where
+	C: PushBack<Item = T> + Len,
+{
+	type Output = C::Output;
+
+	/// Pushes the given item, recording its removal if the sequence grew.
+	fn push_back(&mut self, item: T) -> C::Output {
+		let len = self.inner.len();
+		let output = self.inner.push_back(item);
+		if self.inner.len() > len {
+			self.record(|| VecUndo::PopBack);
+		}
+
+		output
+	}
+}
+
+impl<T, C> PopBack for Transactional<C, VecUndo<T>>
+where
+	T: Clone,
+	C: PopBack<Item = T>,
+{
+	fn pop_back(&mut self) -> Option<T> {
+		let old = self.inner.pop_back();
+
+		if let Some(old) = &old {
+			self.record(|| VecUndo::PushBack(old.clone()))
+		}
+
+		old
+	}
+}
+
+impl<T, C> GetMut<usize> for Transactional<C, VecUndo<T>>
+where
+	T: Clone,
+	C: Collection<Item = T> + GetMut<usize>,
+{
+	fn get_mut(&mut self, index: usize) -> Option<C::ItemMut<'_>> {
+		if self.in_transaction() {
+			if let Some(item) = self.inner.get(index) {
+				let old = (*item).clone();
+				self.log.push(VecUndo::Set(index, old))
+			}
+		}
+
+		self.inner.get_mut(index)
+	}
+}
+
+impl<T, C> IndexMut<usize> for Transactional<C, VecUndo<T>>
+where
+	T: Clone,
+	C: Index<usize, Output = T> + IndexMut<usize> + Len,
+{
+	fn index_mut(&mut self, index: usize) -> &mut T {
+		if self.in_transaction() && index < self.inner.len() {
+			let old = self.inner[index].clone();
+			self.log.push(VecUndo::Set(index, old))
+		}
+
+		&mut self.inner[index]
+	}
+}
+
+impl<T, C> Transactional<C, VecUndo<T>>
+where
+	T: Clone,
+	C: Collection<Item = T> + Get<usize> + Len,
+{
+	/// Records every item at its current index, if a transaction is open,
+	/// so that reordering the sequence can be undone.
+	fn record_items(&mut self) {
+		if self.in_transaction() {
+			for index in 0..self.inner.len() {
+				if let Some(item) = self.inner.get(index) {
+					let old = (*item).clone();
+					self.log.push(VecUndo::Set(index, old))
+				}
+			}
+		}
+	}
+}
+
+impl<T, C> Sort for Transactional<C, VecUndo<T>>
+where
+	T: Clone,
+	C: Sort + Collection<Item = T> + Get<usize> + Len,
+{
+	/// Sorts the sequence, recording every item first.
+	fn sort(&mut self)
+	where
+		T: Ord,
+	{
+		self.record_items();
+		self.inner.sort()
+	}
+
+	fn sort_by<F>(&mut self, compare: F)
+	where
+		F: FnMut(&T, &T) -> Ordering,
+	{
+		self.record_items();
+		self.inner.sort_by(compare)
+	}
+
+	fn sort_unstable_by_key<K, F>(&mut self, f: F)
+	where
+		K: Ord,
+		F: FnMut(&T) -> K,
+	{
+		self.record_items();
+		self.inner.sort_unstable_by_key(f)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::{MapUndo, SetUndo, Transactional, VecUndo};
+	use crate::{
+		bounded::Reject, Bounded, Get, GetMut, Insert, MapInsert, PopBack, PushBack, Remove, Sort,
+	};
+	use std::collections::{BTreeMap, BTreeSet};
+
+	type Map = Transactional<BTreeMap<u32, u32>, MapUndo<u32, u32>>;
+
+	/// Inserts the given bindings, failing on the first key already present.
+	fn insert_new(map: &mut Map, bindings: &[(u32, u32)]) -> Result<(), u32> {
+		for &(key, value) in bindings {
+			if map.insert(key, value).is_some() {
+				return Err(key);
+			}
+		}
+
+		Ok(())
+	}
+
+	#[test]
+	fn rollback_after_partial_failure() {
+		let mut map = Map::default();
+		map.insert(3, 30);
+
+		map.begin();
+		assert_eq!(
+			insert_new(&mut map, &[(1, 10), (2, 20), (3, 31), (4, 40)]),
+			Err(3)
+		);
+		assert!(map.rollback());
+
+		assert_eq!(map.inner().len(), 1);
+		assert_eq!(map.get(&3), Some(&30));
+		assert!(!map.in_transaction());
+	}
+
+	#[test]
+	fn nested_commit_is_reverted_by_outer_rollback() {
+		let mut map = Map::default();
+
+		map.begin();
+		map.insert(1, 10);
+		map.begin();
+		map.remove(&1);
+		map.insert(2, 20);
+		assert!(map.commit());
+		assert_eq!(map.depth(), 1);
+		assert_eq!(map.get(&2), Some(&20));
+
+		assert!(map.rollback());
+		assert!(map.inner().is_empty());
+	}
+
+	#[test]
+	fn inner_rollback_keeps_outer_changes() {
+		let mut map = Map::default();
+
+		map.begin();
+		map.insert(1, 10);
+		map.begin();
+		*map.get_mut(&1).unwrap() = 11;
+		map.insert(2, 20);
+		assert!(map.rollback());
+
+		assert_eq!(map.get(&1), Some(&10));
+		assert_eq!(map.get(&2), None);
+		assert!(map.commit());
+		assert_eq!(map.into_inner().len(), 1);
+	}
+
+	#[test]
+	fn no_transaction() {
+		let mut map = Map::default();
+		map.insert(1, 10);
+		assert!(!map.commit());
+		assert!(!map.rollback());
+		assert_eq!(map.get(&1), Some(&10));
+	}
+
+	#[test]
+	fn set_duplicates_are_not_undone() {
+		let mut set: Transactional<BTreeSet<u32>, SetUndo<u32>> = Transactional::default();
+		set.insert(1);
+
+		set.begin();
+		assert!(!set.insert(1));
+		assert!(set.insert(2));
+		assert_eq!(set.remove(&3), None);
+		assert_eq!(set.remove(&1), Some(1));
+		set.rollback();
+
+		assert_eq!(set.inner().iter().copied().collect::<Vec<_>>(), [1]);
+	}
+
+	#[test]
+	fn vec_mutations_are_undone_in_reverse_order() {
+		let mut vec: Transactional<Vec<u32>, VecUndo<u32>> = Transactional::new(vec![1, 2]);
+
+		vec.begin();
+		vec[0] = 10;
+		vec.push_back(3);
+		*vec.get_mut(2).unwrap() = 30;
+		vec.pop_back();
+		vec.pop_back();
+		assert_eq!(vec.inner(), &[10]);
+		vec.rollback();
+
+		assert_eq!(vec.inner(), &[1, 2]);
+	}
+
+	#[test]
+	fn rejected_pushes_are_not_undone() {
+		let mut vec: Transactional<Bounded<Vec<u32>, Reject>, VecUndo<u32>> =
+			Transactional::new(Bounded::with_policy(vec![1], 2, Reject));
+
+		vec.begin();
+		assert_eq!(vec.push_back(2), Ok(()));
+		assert_eq!(vec.push_back(3), Err(3));
+		vec.rollback();
+
+		assert_eq!(vec.inner().inner(), &[1]);
+	}
+
+	#[test]
+	fn sort_is_undone() {
+		let mut vec: Transactional<Vec<u32>, VecUndo<u32>> = Transactional::new(vec![3, 1, 2]);
+
+		vec.begin();
+		vec.sort();
+		assert_eq!(vec.inner(), &[1, 2, 3]);
+		vec.push_back(0);
+		vec.sort_unstable_by_key(|i| *i);
+		assert_eq!(vec.inner(), &[0, 1, 2, 3]);
+		vec.rollback();
+		assert_eq!(vec.inner(), &[3, 1, 2]);
+
+		vec.sort_by(|a, b| b.cmp(a));
+		assert_eq!(vec.inner(), &[3, 2, 1]);
+	}
+}
@@ -0,0 +1,456 @@
+//! Map backed by a sorted sequence.
+use crate::{
+	sorted::{insert_at, partition_point, remove_at},
+	Clear, Collection, CollectionMut, CollectionRef, Get, GetKeyValue, GetKeyValueMut, GetMut,
+	IterMut, Keyed, KeyedRef, Len, MapInsert, MapIter, MapIterMut, PopBack, PushBack, Remove,
+	SimpleCollectionMut, SimpleCollectionRef, SimpleKeyedRef,
+};
+use core::{
+	borrow::Borrow,
+	marker::PhantomData,
+	ops::{Index, IndexMut},
+};
+
+/// Map backed by a sequence of key-value pairs sorted by key.
+///
+/// The storage `C` of the `(K, V)` pairs can be any vector-like collection
+/// (such as a [`VecMut<(K, V)>`](crate::VecMut)).
+/// Keys are found using a binary search.
+/// For small maps, this is usually faster than a hash map,
+/// and provides a `no_std` map for any `no_std` vector implementation.
+///
+/// ## Example
+///
+/// ```
+/// use cc_traits::{Get, MapInsert, MapMut, VecMap};
+///
+/// fn fill<M: MapMut<&'static str, i32>>(map: &mut M) {
+///   map.insert("b", 2);
+///   map.insert("a", 1);
+///   map.insert("c", 3);
+/// }
+///
+/// let mut map: VecMap<&str, i32, Vec<_>> = VecMap::new();
+/// fill(&mut map);
+///
+/// assert_eq!(map.get(&"b"), Some(&2));
+/// assert_eq!(map.inner(), &[("a", 1), ("b", 2), ("c", 3)]);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct VecMap<K, V, C> {
+	inner: C,
+	pairs: PhantomData<(K, V)>,
+}
+
+impl<K, V, C: Default> Default for VecMap<K, V, C> {
+	fn default() -> Self {
+		Self::new()
+	}
+}
+
+impl<K, V, C> VecMap<K, V, C> {
+	/// Creates a new empty map.
+	#[inline(always)]
+	pub fn new() -> Self
+	where
+		C: Default,
+	{
+		Self {
+			inner: C::default(),
+			pairs: PhantomData,
+		}
+	}
+
+	/// Returns a reference to the inner sequence of key-value pairs.
+	#[inline(always)]
+	pub fn inner(&self) -> &C {
+		&self.inner
+	}
+
+	/// Consumes the map and returns the inner sequence of key-value pairs.
+	#[inline(always)]
+	pub fn into_inner(self) -> C {
+		self.inner
+	}
+
+	/// Returns an iterator over the key-value pairs of the map, in ascending key order.
+	#[inline(always)]
+	pub fn iter(&self) -> Iter<'_, K, V, C>
+	where
+		C: Len,
+	{
+		Iter {
+			inner: &self.inner,
+			start: 0,
+			end: self.inner.len(),
+			pairs: PhantomData,
+		}
+	}
+
+	/// Binary search of the given key.
+	///
+	/// Returns `Ok(index)` with the index of the entry with the given key,
+	/// or `Err(index)` with the index where this key would be inserted.
+	pub fn binary_search<Q>(&self, key: &Q) -> Result<usize, usize>
+	where
+		C: Len + Index<usize, Output = (K, V)>,
+		K: Borrow<Q>,
+		Q: ?Sized + Ord,
+	{
+		let i = partition_point(&self.inner, |(k, _)| k.borrow() < key);
+		if i < self.inner.len() && self.inner[i].0.borrow() == key {
+			Ok(i)
+		} else {
+			Err(i)
+		}
+	}
+}
+
+impl<K, V, C> Collection for VecMap<K, V, C> {
+	type Item = V;
+}
+
+impl<K, V, C> CollectionRef for VecMap<K, V, C> {
+	type ItemRef<'a> = &'a V where Self: 'a;
+
+	crate::covariant_item_ref!();
+}
+
+impl<K, V, C> CollectionMut for VecMap<K, V, C> {
+	type ItemMut<'a> = &'a mut V where Self: 'a;
+
+	crate::covariant_item_mut!();
+}
+
+impl<K, V, C> SimpleCollectionRef for VecMap<K, V, C> {
+	crate::simple_collection_ref!();
+}
+
+impl<K, V, C> SimpleCollectionMut for VecMap<K, V, C> {
+	crate::simple_collection_mut!();
+}
+
+impl<K, V, C> Keyed for VecMap<K, V, C> {
+	type Key = K;
+}
+
+impl<K, V, C> KeyedRef for VecMap<K, V, C> {
+	type KeyRef<'a> = &'a K where Self: 'a;
+
+	crate::covariant_key_ref!();
+}
+
+impl<K, V, C> SimpleKeyedRef for VecMap<K, V, C> {
+	crate::simple_keyed_ref!();
+}
+
+impl<K, V, C: Len> Len for VecMap<K, V, C> {
+	#[inline(always)]
+	fn len(&self) -> usize {
+		self.inner.len()
+	}
+
+	#[inline(always)]
+	fn is_empty(&self) -> bool {
+		self.inner.is_empty()
+	}
+}
+
+impl<'a, Q: ?Sized, K, V, C> Get<&'a Q> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + Index<usize, Output = (K, V)>,
+	K: Borrow<Q>,
+	Q: Ord,
+{
+	#[inline(always)]
+	fn get(&self, key: &'a Q) -> Option<&V> {
+		self.binary_search(key).ok().map(|i| &self.inner[i].1)
+	}
+}
+
+impl<'a, Q: ?Sized, K, V, C> GetMut<&'a Q> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + IndexMut<usize, Output = (K, V)>,
+	K: Borrow<Q>,
+	Q: Ord,
+{
+	#[inline(always)]
+	fn get_mut(&mut self, key: &'a Q) -> Option<&mut V> {
+		let i = self.binary_search(key).ok()?;
+		Some(&mut self.inner[i].1)
+	}
+}
+
+impl<'a, Q: ?Sized, K, V, C> GetKeyValue<&'a Q> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + Index<usize, Output = (K, V)>,
+	K: Borrow<Q>,
+	Q: Ord,
+{
+	#[inline(always)]
+	fn get_key_value(&self, key: &'a Q) -> Option<(&K, &V)> {
+		self.binary_search(key).ok().map(|i| {
+			let (k, v) = &self.inner[i];
+			(k, v)
+		})
+	}
+}
+
+impl<'a, Q: ?Sized, K, V, C> GetKeyValueMut<&'a Q> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + IndexMut<usize, Output = (K, V)>,
+	K: Borrow<Q>,
+	Q: Ord,
+{
+	#[inline(always)]
+	fn get_key_value_mut(&mut self, key: &'a Q) -> Option<(&K, &mut V)> {
+		let i = self.binary_search(key).ok()?;
+		let (k, v) = &mut self.inner[i];
+		Some((&*k, v))
+	}
+}
+
+impl<K: Ord, V, C> MapInsert<K> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + PushBack + IndexMut<usize, Output = (K, V)>,
+{
+	type Output = Option<V>;
+
+	fn insert(&mut self, key: K, value: V) -> Option<V> {
+		match self.binary_search(&key) {
+			Ok(i) => Some(core::mem::replace(&mut self.inner[i].1, value)),
+			Err(i) => {
+				insert_at(&mut self.inner, i, (key, value));
+				None
+			}
+		}
+	}
+}
+
+impl<'a, Q: ?Sized, K, V, C> Remove<&'a Q> for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + PopBack + IndexMut<usize, Output = (K, V)>,
+	K: Borrow<Q>,
+	Q: Ord,
+{
+	fn remove(&mut self, key: &'a Q) -> Option<V> {
+		let i = self.binary_search(key).ok()?;
+		remove_at(&mut self.inner, i).map(|(_, v)| v)
+	}
+}
+
+impl<K, V, C: Clear> Clear for VecMap<K, V, C> {
+	#[inline(always)]
+	fn clear(&mut self) {
+		self.inner.clear()
+	}
+}
+
+impl<K, V, C> crate::Iter for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + Index<usize, Output = (K, V)>,
+{
+	type Iter<'a> = Values<'a, K, V, C> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		Values { inner: self.iter() }
+	}
+}
+
+impl<K, V, C> MapIter for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + Len + Index<usize, Output = (K, V)>,
+{
+	type Iter<'a> = Iter<'a, K, V, C> where Self: 'a;
+
+	#[inline(always)]
+	fn iter(&self) -> Self::Iter<'_> {
+		self.iter()
+	}
+}
+
+impl<K, V, C> MapIterMut for VecMap<K, V, C>
+where
+	C: Collection<Item = (K, V)> + IterMut + SimpleCollectionMut,
+{
+	type IterMut<'a> = EntriesMut<'a, K, V, C> where Self: 'a;
+
+	#[inline(always)]
+	fn iter_mut(&mut self) -> Self::IterMut<'_> {
+		EntriesMut {
+			inner: self.inner.iter_mut(),
+			pairs: PhantomData,
+		}
+	}
+}
+
+/// Iterator over the key-value pairs of a [`VecMap`], in ascending key order.
+pub struct Iter<'a, K, V, C> {
+	inner: &'a C,
+	start: usize,
+	end: usize,
+	pairs: PhantomData<&'a (K, V)>,
+}
+
+impl<'a, K, V, C> Clone for Iter<'a, K, V, C> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner,
+			start: self.start,
+			end: self.end,
+			pairs: PhantomData,
+		}
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> Iterator for Iter<'a, K, V, C> {
+	type Item = (&'a K, &'a V);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		let len = self.end - self.start;
+		(len, Some(len))
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			let (k, v) = &self.inner[self.start];
+			self.start += 1;
+			Some((k, v))
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> DoubleEndedIterator for Iter<'a, K, V, C> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		if self.start < self.end {
+			self.end -= 1;
+			let (k, v) = &self.inner[self.end];
+			Some((k, v))
+		} else {
+			None
+		}
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> ExactSizeIterator for Iter<'a, K, V, C> {}
+
+/// Iterator over the values of a [`VecMap`], in ascending key order.
+pub struct Values<'a, K, V, C> {
+	inner: Iter<'a, K, V, C>,
+}
+
+impl<'a, K, V, C> Clone for Values<'a, K, V, C> {
+	fn clone(&self) -> Self {
+		Self {
+			inner: self.inner.clone(),
+		}
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> Iterator for Values<'a, K, V, C> {
+	type Item = &'a V;
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|(_, v)| v)
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> DoubleEndedIterator for Values<'a, K, V, C> {
+	fn next_back(&mut self) -> Option<Self::Item> {
+		self.inner.next_back().map(|(_, v)| v)
+	}
+}
+
+impl<'a, K, V, C: Index<usize, Output = (K, V)>> ExactSizeIterator for Values<'a, K, V, C> {}
+
+/// Iterator over the key-value pairs of a [`VecMap`], with mutable references to the values.
+pub struct EntriesMut<'a, K, V, C: IterMut + 'a> {
+	inner: C::IterMut<'a>,
+	pairs: PhantomData<&'a mut (K, V)>,
+}
+
+impl<'a, K, V, C> Iterator for EntriesMut<'a, K, V, C>
+where
+	C: Collection<Item = (K, V)> + IterMut + SimpleCollectionMut,
+{
+	type Item = (&'a K, &'a mut V);
+
+	fn size_hint(&self) -> (usize, Option<usize>) {
+		self.inner.size_hint()
+	}
+
+	fn next(&mut self) -> Option<Self::Item> {
+		self.inner.next().map(|r| {
+			let (k, v) = C::into_mut(r);
+			(&*k, v)
+		})
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::VecMap;
+	use crate::{Clear, Get, GetKeyValue, GetMut, Len, MapInsert, MapIter, MapIterMut, Remove};
+	use std::{collections::VecDeque, string::String};
+
+	type Map = VecMap<String, u32, VecDeque<(String, u32)>>;
+
+	#[test]
+	fn keys_stay_sorted() {
+		let mut map = Map::new();
+		for (i, key) in ["d", "b", "e", "a", "c"].iter().enumerate() {
+			assert_eq!(map.insert(key.to_string(), i as u32), None);
+		}
+
+		let keys: Vec<_> = MapIter::iter(&map).map(|(k, _)| k.as_str()).collect();
+		assert_eq!(keys, ["a", "b", "c", "d", "e"]);
+		assert_eq!(
+			map.iter().next_back().map(|(k, v)| (k.as_str(), *v)),
+			Some(("e", 2))
+		);
+	}
+
+	#[test]
+	fn borrowed_lookups() {
+		let mut map = Map::new();
+		map.insert("a".into(), 1);
+		map.insert("b".into(), 2);
+
+		assert_eq!(map.insert("a".into(), 10), Some(1));
+		assert_eq!(map.get("a"), Some(&10));
+		assert_eq!(map.get_key_value("b"), Some((&"b".to_string(), &2)));
+		*map.get_mut("b").unwrap() += 1;
+		for (_, v) in map.iter_mut() {
+			*v *= 2
+		}
+		assert_eq!(map.get("b"), Some(&6));
+		assert_eq!(map.binary_search("ab"), Err(1));
+	}
+
+	#[test]
+	fn removal() {
+		let mut map = Map::new();
+		for key in ["a", "b", "c"].iter() {
+			map.insert(key.to_string(), 0);
+		}
+
+		assert_eq!(map.remove("b"), Some(0));
+		assert_eq!(map.remove("b"), None);
+		assert_eq!(map.remove(""), None);
+		assert_eq!(map.len(), 2);
+		assert!(!map.contains("b"));
+		assert!(map.contains("c"));
+
+		map.clear();
+		assert!(map.is_empty());
+		assert_eq!(map.insert(String::new(), 1), None);
+		assert_eq!(map.into_inner(), [(String::new(), 1)]);
+	}
+}
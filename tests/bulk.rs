@@ -0,0 +1,143 @@
+#![cfg(feature = "std")]
+use cc_traits::{
+	Collection, Insert, InsertAll, MapInsert, MapInsertAll, PushBack, PushBackAll, Remove,
+	RemoveAll,
+};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Collection using the provided bulk methods, logging its operations.
+#[derive(Default)]
+struct Log {
+	items: Vec<(i32, i32)>,
+	reserved: Vec<usize>,
+}
+
+impl Collection for Log {
+	type Item = i32;
+}
+
+impl PushBack for Log {
+	type Output = ();
+
+	fn push_back(&mut self, item: i32) {
+		self.items.push((item, item))
+	}
+}
+
+impl PushBackAll for Log {
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserved.push(additional)
+	}
+}
+
+impl Insert for Log {
+	type Output = ();
+
+	fn insert(&mut self, item: i32) {
+		self.items.push((item, item))
+	}
+}
+
+impl InsertAll for Log {
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserved.push(additional)
+	}
+}
+
+impl MapInsert<i32> for Log {
+	type Output = ();
+
+	fn insert(&mut self, key: i32, value: i32) {
+		self.items.push((key, value))
+	}
+}
+
+impl MapInsertAll<i32> for Log {
+	fn reserve_all(&mut self, additional: usize) {
+		self.reserved.push(additional)
+	}
+}
+
+impl Remove<i32> for Log {
+	fn remove(&mut self, key: i32) -> Option<i32> {
+		let index = self.items.iter().position(|(k, _)| *k == key)?;
+		Some(self.items.remove(index).1)
+	}
+}
+
+impl RemoveAll<i32> for Log {}
+
+#[test]
+fn default_push_back_all() {
+	let mut log = Log::default();
+	log.push_back_all(vec![1, 2]);
+	log.push_back_slice(&[3]);
+	log.push_back_all((4..10).filter(|i| i % 2 == 0));
+	assert_eq!(log.items, [(1, 1), (2, 2), (3, 3), (4, 4), (6, 6), (8, 8)]);
+	assert_eq!(log.reserved, [2, 1, 0]);
+}
+
+#[test]
+fn default_insert_all() {
+	let mut log = Log::default();
+	InsertAll::insert_all(&mut log, vec![1, 2]);
+	MapInsertAll::insert_all(&mut log, vec![(3, 4)]);
+	assert_eq!(log.items, [(1, 1), (2, 2), (3, 4)]);
+	assert_eq!(log.reserved, [2, 1]);
+}
+
+#[test]
+fn default_remove_all() {
+	let mut log = Log::default();
+	log.push_back_all(vec![1, 2, 3]);
+	assert_eq!(log.remove_all(vec![3, 4, 1, 1]), 2);
+	assert_eq!(log.items, [(2, 2)]);
+}
+
+#[test]
+fn native_push_back_all() {
+	let mut v = vec![1];
+	v.push_back_all(vec![2, 3]);
+	v.push_back_slice(&[4, 5]);
+	assert_eq!(v, [1, 2, 3, 4, 5]);
+
+	let mut v: Vec<i32> = Vec::new();
+	v.reserve_all(10);
+	assert!(v.capacity() >= 10);
+
+	let mut deque = VecDeque::from(vec![1]);
+	deque.push_back_all(2..4);
+	deque.push_back_slice(&[4]);
+	assert_eq!(deque, [1, 2, 3, 4]);
+}
+
+#[test]
+fn native_insert_all() {
+	let mut set = HashSet::new();
+	set.insert_all(vec![1, 2, 1]);
+	assert_eq!(set, HashSet::from([1, 2]));
+
+	let mut set = BTreeSet::from([3]);
+	set.insert_all(vec![1, 2]);
+	assert_eq!(set.into_iter().collect::<Vec<_>>(), [1, 2, 3]);
+
+	let mut map = HashMap::from([("a", 1)]);
+	map.reserve_all(10);
+	assert!(map.capacity() >= 11);
+	map.insert_all(vec![("a", 2), ("b", 3)]);
+	assert_eq!(map, HashMap::from([("a", 2), ("b", 3)]));
+
+	let mut map = BTreeMap::new();
+	map.insert_all(vec![("b", 1), ("a", 2), ("b", 3)]);
+	assert_eq!(map.into_iter().collect::<Vec<_>>(), [("a", 2), ("b", 3)]);
+}
+
+#[cfg(feature = "smallvec")]
+#[test]
+fn smallvec_push_back_all() {
+	let mut v: smallvec::SmallVec<[i32; 2]> = smallvec::smallvec![1];
+	v.push_back_slice(&[2, 3]);
+	v.push_back_all(vec![4]);
+	assert_eq!(v.as_slice(), [1, 2, 3, 4]);
+	assert!(v.spilled());
+}
@@ -0,0 +1,50 @@
+#![cfg(all(feature = "serde_json", feature = "toml", feature = "serde_yaml"))]
+use cc_traits::{MapIter, MapMut, SimpleCollectionRef, SimpleKeyedRef};
+
+/// Copies the bindings of `defaults` that are missing from `config`.
+fn merge_defaults<K, V, M>(config: &mut M, defaults: &M)
+where
+	K: Clone,
+	V: Clone,
+	M: MapMut<K, V> + MapIter + SimpleKeyedRef + SimpleCollectionRef,
+{
+	for (key, value) in defaults.iter() {
+		let key = <M as SimpleKeyedRef>::into_ref(key);
+		if !config.contains(key) {
+			config.insert(
+				key.clone(),
+				<M as SimpleCollectionRef>::into_ref(value).clone(),
+			);
+		}
+	}
+}
+
+#[test]
+fn json_config() {
+	let mut config = serde_json::json!({ "a": 1 }).as_object().unwrap().clone();
+	let defaults = serde_json::json!({ "a": 0, "b": 2 })
+		.as_object()
+		.unwrap()
+		.clone();
+	merge_defaults(&mut config, &defaults);
+	assert_eq!(
+		serde_json::Value::from(config),
+		serde_json::json!({ "a": 1, "b": 2 })
+	);
+}
+
+#[test]
+fn toml_config() {
+	let mut config: toml::Table = toml::from_str("a = 1").unwrap();
+	let defaults: toml::Table = toml::from_str("a = 0\nb = 2").unwrap();
+	merge_defaults(&mut config, &defaults);
+	assert_eq!(config, toml::from_str("a = 1\nb = 2").unwrap());
+}
+
+#[test]
+fn yaml_config() {
+	let mut config: serde_yaml::Mapping = serde_yaml::from_str("a: 1").unwrap();
+	let defaults: serde_yaml::Mapping = serde_yaml::from_str("a: 0\nb: 2").unwrap();
+	merge_defaults(&mut config, &defaults);
+	assert_eq!(config, serde_yaml::from_str("a: 1\nb: 2").unwrap());
+}
@@ -0,0 +1,90 @@
+#![cfg(all(feature = "serde_json", feature = "ijson"))]
+use cc_traits::{Back, Front, GetKeyValueMut, Iter, MapMut, WithCapacity};
+
+fn assert_map_mut<K, V, M: MapMut<K, V>>() {}
+
+#[test]
+fn serde_json_object_is_map_mut() {
+	assert_map_mut::<String, serde_json::Value, serde_json::Map<String, serde_json::Value>>()
+}
+
+#[test]
+fn ijson_object_is_map_mut() {
+	assert_map_mut::<ijson::IString, ijson::IValue, ijson::IObject>()
+}
+
+#[test]
+fn serde_json_object_access() {
+	let mut object: serde_json::Map<String, serde_json::Value> = WithCapacity::with_capacity(1);
+	object.insert("a".to_string(), 1.into());
+
+	assert_eq!(GetKeyValueMut::get_key_value_mut(&mut object, "b"), None);
+	let (key, value) = GetKeyValueMut::get_key_value_mut(&mut object, "a").unwrap();
+	assert_eq!(key, "a");
+	*value = 2.into();
+
+	assert_eq!(
+		Iter::iter(&object).collect::<Vec<_>>(),
+		[&serde_json::Value::from(2)]
+	);
+}
+
+#[test]
+fn ijson_object_access() {
+	let mut object: ijson::IObject = WithCapacity::with_capacity(1);
+	object.insert("a", 1);
+
+	let key = "a".to_string();
+	assert_eq!(
+		cc_traits::Get::get(&object, &key),
+		Some(&ijson::IValue::from(1))
+	);
+	assert_eq!(
+		Iter::iter(&object).collect::<Vec<_>>(),
+		[&ijson::IValue::from(1)]
+	);
+}
+
+#[test]
+fn ijson_object_nested_reference_access() {
+	let mut object = ijson::IObject::new();
+	object.insert("a", 1);
+
+	let key = ijson::IString::from("a");
+	assert_eq!(
+		cc_traits::Get::get(&object, &&"a"),
+		Some(&ijson::IValue::from(1))
+	);
+	assert_eq!(
+		cc_traits::Get::get(&object, &&key),
+		Some(&ijson::IValue::from(1))
+	);
+	assert_eq!(
+		cc_traits::Remove::remove(&mut object, &&"a"),
+		Some(ijson::IValue::from(1))
+	);
+}
+
+#[test]
+fn serde_json_value_front_back() {
+	let array = serde_json::json!([1, 2]);
+	assert_eq!(Front::front(&array), Some(&serde_json::Value::from(1)));
+	assert_eq!(Back::back(&array), Some(&serde_json::Value::from(2)));
+
+	let object = serde_json::json!({ "a": 1 });
+	assert_eq!(Front::front(&object), None);
+	assert_eq!(Back::back(&object), None);
+	assert_eq!(Front::front(&serde_json::Value::Null), None);
+}
+
+#[test]
+fn ijson_value_front_back() {
+	let array = ijson::ijson!([1, 2]);
+	assert_eq!(Front::front(&array), Some(&ijson::IValue::from(1)));
+	assert_eq!(Back::back(&array), Some(&ijson::IValue::from(2)));
+
+	let object = ijson::ijson!({ "a": 1 });
+	assert_eq!(Front::front(&object), None);
+	assert_eq!(Back::back(&object), None);
+	assert_eq!(Front::front(&ijson::IValue::NULL), None);
+}
@@ -0,0 +1,170 @@
+#![cfg(feature = "std")]
+use cc_traits::{Append, Len, SplitOff};
+use std::collections::{BTreeMap, BTreeSet, HashMap, HashSet, VecDeque};
+
+/// Appends `other` to `collection`, without duplicates, and checks that
+/// `other` is left empty.
+fn append<C: Append + Len>(mut collection: C, mut other: C) -> C {
+	let len = collection.len() + other.len();
+	collection.append(&mut other);
+	assert!(other.is_empty());
+	assert_eq!(collection.len(), len);
+	collection
+}
+
+/// Splits a sequence of length 3 at its ends.
+fn split_ends<C: SplitOff<usize> + Len + Clone>(sequence: C) {
+	let mut head = sequence.clone();
+	let tail = head.split_off(0);
+	assert!(head.is_empty());
+	assert_eq!(tail.len(), 3);
+
+	let mut head = sequence;
+	let tail = head.split_off(3);
+	assert_eq!(head.len(), 3);
+	assert!(tail.is_empty());
+}
+
+#[test]
+fn append_leaves_other_empty() {
+	assert_eq!(append(vec![1], vec![2, 3]), [1, 2, 3]);
+	assert_eq!(
+		append(VecDeque::from(vec![1]), VecDeque::from(vec![2])),
+		[1, 2]
+	);
+	assert_eq!(
+		append(HashSet::from([1]), HashSet::from([2])),
+		HashSet::from([1, 2])
+	);
+	assert_eq!(
+		append(BTreeSet::from([1]), BTreeSet::from([2])),
+		BTreeSet::from([1, 2])
+	);
+	assert_eq!(
+		append(HashMap::from([(1, 'a')]), HashMap::from([(2, 'b')])),
+		HashMap::from([(1, 'a'), (2, 'b')])
+	);
+	assert_eq!(
+		append(BTreeMap::from([(1, 'a')]), BTreeMap::from([(2, 'b')])),
+		BTreeMap::from([(1, 'a'), (2, 'b')])
+	);
+}
+
+#[test]
+fn append_overwrites_duplicate_keys() {
+	let mut map = BTreeMap::from([(1, 'a'), (2, 'b')]);
+	let mut other = BTreeMap::from([(2, 'c')]);
+	Append::append(&mut map, &mut other);
+	assert!(other.is_empty());
+	assert_eq!(map, BTreeMap::from([(1, 'a'), (2, 'c')]));
+}
+
+#[test]
+fn split_off_sequences() {
+	split_ends(vec![1, 2, 3]);
+	split_ends(VecDeque::from(vec![1, 2, 3]));
+
+	let mut v = vec![1, 2, 3];
+	assert_eq!(SplitOff::split_off(&mut v, 1), [2, 3]);
+	assert_eq!(v, [1]);
+}
+
+#[test]
+#[should_panic]
+fn vec_split_off_past_len() {
+	SplitOff::split_off(&mut vec![1, 2, 3], 4);
+}
+
+#[test]
+#[should_panic]
+fn deque_split_off_past_len() {
+	SplitOff::split_off(&mut VecDeque::from(vec![1, 2, 3]), 4);
+}
+
+#[test]
+fn split_off_ordered_collections() {
+	let mut map = BTreeMap::from([(1, 'a'), (3, 'b'), (5, 'c')]);
+	let tail = SplitOff::split_off(&mut map, &2);
+	assert_eq!(map, BTreeMap::from([(1, 'a')]));
+	assert_eq!(tail, BTreeMap::from([(3, 'b'), (5, 'c')]));
+
+	let mut tail = tail;
+	assert!(SplitOff::split_off(&mut tail, &6).is_empty());
+	assert_eq!(SplitOff::split_off(&mut tail, &0).len(), 2);
+	assert!(tail.is_empty());
+
+	let mut set = BTreeSet::from([1, 3, 5]);
+	assert_eq!(SplitOff::split_off(&mut set, &3), BTreeSet::from([3, 5]));
+	assert_eq!(set, BTreeSet::from([1]));
+}
+
+#[cfg(feature = "smallvec")]
+mod smallvec {
+	use super::{append, split_ends};
+	use cc_traits::SplitOff;
+	use smallvec::{smallvec, SmallVec};
+
+	#[test]
+	fn append_and_split_off() {
+		let v: SmallVec<[i32; 2]> = append(smallvec![1], smallvec![2, 3]);
+		assert_eq!(v.as_slice(), [1, 2, 3]);
+		split_ends(v.clone());
+
+		let mut v = v;
+		assert_eq!(SplitOff::split_off(&mut v, 1).as_slice(), [2, 3]);
+		assert_eq!(v.as_slice(), [1]);
+	}
+
+	#[test]
+	#[should_panic]
+	fn split_off_past_len() {
+		let mut v: SmallVec<[i32; 2]> = smallvec![1, 2, 3];
+		SplitOff::split_off(&mut v, 4);
+	}
+}
+
+#[cfg(feature = "ijson")]
+mod ijson {
+	use super::{append, split_ends};
+	use cc_traits::SplitOff;
+	use ijson::{ijson, IArray, IObject};
+
+	#[test]
+	fn append_and_split_off() {
+		let array = |value: ijson::IValue| value.into_array().unwrap();
+		let a: IArray = append(array(ijson!([1])), array(ijson!([2, 3])));
+		assert_eq!(a, array(ijson!([1, 2, 3])));
+		split_ends(a.clone());
+
+		let mut a = a;
+		assert_eq!(SplitOff::split_off(&mut a, 1), array(ijson!([2, 3])));
+		assert_eq!(a, array(ijson!([1])));
+
+		let object = |value: ijson::IValue| value.into_object().unwrap();
+		let o: IObject = append(object(ijson!({ "a": 1 })), object(ijson!({ "b": 2 })));
+		assert_eq!(o, object(ijson!({ "a": 1, "b": 2 })));
+	}
+
+	#[test]
+	#[should_panic]
+	fn split_off_past_len() {
+		let mut a = ijson!([1, 2, 3]).into_array().unwrap();
+		SplitOff::split_off(&mut a, 4);
+	}
+}
+
+#[cfg(feature = "serde_json")]
+#[test]
+fn serde_json_append() {
+	let object = |value: serde_json::Value| match value {
+		serde_json::Value::Object(object) => object,
+		_ => unreachable!(),
+	};
+	assert_eq!(
+		append(
+			object(serde_json::json!({ "a": 1 })),
+			object(serde_json::json!({ "b": 2 }))
+		),
+		object(serde_json::json!({ "a": 1, "b": 2 }))
+	);
+}